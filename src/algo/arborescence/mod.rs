@@ -0,0 +1,260 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::{
+    traits::Graph,
+    unstable_graph::{Edge, IndexType, UnstableGraph, VertexIndex},
+    Acyclic, Cyclicness, Directed, EdgePolicy,
+};
+use std::ops::{Add, Sub};
+
+const NONE: usize = usize::MAX;
+
+/// Finds a minimum spanning arborescence of `graph` rooted at `root`: the set of edges of least
+/// total `cost` through which every vertex can be reached from `root` along exactly one path.
+/// Uses the [Chu–Liu/Edmonds](https://en.wikipedia.org/wiki/Edmonds%27_algorithm) algorithm.
+///
+/// Returns the chosen edges, one entering each vertex other than `root`, or [`None`] if `root`
+/// is not in `graph` or some vertex cannot be reached from it. Undirected edges can be used in
+/// either direction.
+pub fn minimum_spanning_arborescence<G, F, W>(
+    graph: &G,
+    root: G::VertexId,
+    mut cost: F,
+) -> Option<Vec<G::EdgeId>>
+where
+    G: Graph + ?Sized,
+    F: FnMut(&G::EdgeWeight) -> W,
+    W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W>,
+{
+    let indexed = Indexed::new(graph);
+    let root = *indexed.positions.get(&root)?;
+
+    let mut ids = vec![];
+    let mut arcs = vec![];
+
+    for edge in &indexed.edges {
+        let weight = cost(edge.weight);
+
+        arcs.push(Arc::new(edge.source, edge.target, weight, ids.len()));
+        if !indexed.directed {
+            arcs.push(Arc::new(edge.target, edge.source, weight, ids.len()));
+        }
+
+        ids.push(edge.id);
+    }
+
+    let chosen = contract(indexed.vertex_count(), root, arcs)?;
+    Some(chosen.into_iter().map(|edge| ids[edge]).collect())
+}
+
+/// Finds a minimum spanning arborescence of `graph` rooted at `root` as with
+/// [`minimum_spanning_arborescence`], and returns it as a new acyclic graph with the same
+/// vertices at the same indices.
+pub fn minimum_spanning_arborescence_graph<V, E, C, P, Ix, F, W>(
+    graph: &UnstableGraph<V, E, Directed, C, P, Ix>,
+    root: VertexIndex<Ix>,
+    cost: F,
+) -> Option<UnstableGraph<V, E, Directed, Acyclic, P, Ix>>
+where
+    V: Clone,
+    E: Clone,
+    C: Cyclicness,
+    P: EdgePolicy,
+    Ix: IndexType,
+    F: FnMut(&E) -> W,
+    W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W>,
+{
+    let edges = minimum_spanning_arborescence(graph, root, cost)?;
+    let mut arborescence = UnstableGraph::<V, E, Directed, Acyclic, P, Ix>::directed_with_capacity(
+        graph.vertex_count(),
+    );
+
+    // The arborescence has exactly the vertices of `graph`, so `Ix` can index all of them.
+    for vertex in &graph.vertices {
+        arborescence.insert_vertex_unchecked(vertex.weight.clone());
+    }
+
+    for edge in edges {
+        let (source, target) = graph.edges[edge.index()].vertex_indices();
+        let weight = graph[edge].clone();
+
+        // Every vertex has at most one parent, so no edge can close a cycle.
+        arborescence.insert_edge_unchecked(Edge::new(source, target, weight));
+    }
+
+    Some(arborescence)
+}
+
+/// A directed edge of one round of contraction, remembering the edge of the round before that it
+/// stands for.
+#[derive(Clone, Copy)]
+struct Arc<W> {
+    source: usize,
+    target: usize,
+    weight: W,
+    origin: usize,
+}
+
+impl<W> Arc<W> {
+    #[inline]
+    fn new(source: usize, target: usize, weight: W, origin: usize) -> Self {
+        Arc {
+            source,
+            target,
+            weight,
+            origin,
+        }
+    }
+}
+
+/// A round of contraction: its arcs, the cheapest arc entering each vertex and the cycles among
+/// those arcs, each of which becomes a single vertex in the next round.
+struct Round<W> {
+    arcs: Vec<Arc<W>>,
+    cheapest: Vec<usize>,
+    cycles: Vec<Vec<usize>>,
+    /// The vertex of the next round that each vertex is contracted into.
+    contracted: Vec<usize>,
+}
+
+/// Repeatedly picks the cheapest arc into every vertex and contracts the cycles that they form
+/// until there are none, then expands the cycles again, keeping all but one arc of each. Returns
+/// the origins of the chosen arcs.
+fn contract<W>(mut count: usize, mut root: usize, mut arcs: Vec<Arc<W>>) -> Option<Vec<usize>>
+where
+    W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W>,
+{
+    let mut rounds = vec![];
+
+    let mut chosen = loop {
+        let mut cheapest = vec![NONE; count];
+
+        for (index, arc) in arcs.iter().enumerate() {
+            if arc.source != arc.target
+                && arc.target != root
+                && (cheapest[arc.target] == NONE || arc.weight < arcs[cheapest[arc.target]].weight)
+            {
+                cheapest[arc.target] = index;
+            }
+        }
+
+        if (0..count).any(|v| v != root && cheapest[v] == NONE) {
+            return None;
+        }
+
+        let cycles = cycles(count, root, &arcs, &cheapest);
+
+        if cycles.is_empty() {
+            break (0..count)
+                .filter(|&v| v != root)
+                .map(|v| cheapest[v])
+                .collect::<Vec<_>>();
+        }
+
+        let mut contracted = vec![NONE; count];
+        for (index, cycle) in cycles.iter().enumerate() {
+            for &vertex in cycle {
+                contracted[vertex] = index;
+            }
+        }
+
+        let mut next_count = cycles.len();
+        for vertex in &mut contracted {
+            if *vertex == NONE {
+                *vertex = next_count;
+                next_count += 1;
+            }
+        }
+
+        let in_cycle = |v: usize| contracted[v] < cycles.len();
+        let next_arcs = arcs
+            .iter()
+            .enumerate()
+            .filter(|(_, arc)| contracted[arc.source] != contracted[arc.target])
+            .map(|(index, arc)| {
+                let weight = match in_cycle(arc.target) {
+                    true => arc.weight - arcs[cheapest[arc.target]].weight,
+                    false => arc.weight,
+                };
+
+                Arc::new(
+                    contracted[arc.source],
+                    contracted[arc.target],
+                    weight,
+                    index,
+                )
+            })
+            .collect();
+
+        root = contracted[root];
+        count = next_count;
+
+        rounds.push(Round {
+            arcs: std::mem::replace(&mut arcs, next_arcs),
+            cheapest,
+            cycles,
+            contracted,
+        });
+    };
+
+    for index in &mut chosen {
+        *index = arcs[*index].origin;
+    }
+
+    while let Some(round) = rounds.pop() {
+        // The arc chosen into each contracted cycle replaces the cycle's own arc into the vertex
+        // it enters at.
+        let mut entered = vec![NONE; round.cycles.len()];
+        for &index in &chosen {
+            let target = round.contracted[round.arcs[index].target];
+            if target < round.cycles.len() {
+                entered[target] = round.arcs[index].target;
+            }
+        }
+
+        for (cycle, &entry) in round.cycles.iter().zip(&entered) {
+            chosen.extend(
+                cycle
+                    .iter()
+                    .filter(|&&vertex| vertex != entry)
+                    .map(|&vertex| round.cheapest[vertex]),
+            );
+        }
+
+        for index in &mut chosen {
+            *index = round.arcs[*index].origin;
+        }
+    }
+
+    Some(chosen)
+}
+
+/// Finds the cycles formed by following the cheapest arc into each vertex backwards.
+fn cycles<W>(count: usize, root: usize, arcs: &[Arc<W>], cheapest: &[usize]) -> Vec<Vec<usize>> {
+    let mut walk = vec![NONE; count];
+    let mut cycles = vec![];
+
+    for start in 0..count {
+        let mut vertex = start;
+
+        while vertex != root && walk[vertex] == NONE {
+            walk[vertex] = start;
+            vertex = arcs[cheapest[vertex]].source;
+        }
+
+        if vertex != root && walk[vertex] == start {
+            let mut cycle = vec![vertex];
+            let mut next = arcs[cheapest[vertex]].source;
+
+            while next != vertex {
+                cycle.push(next);
+                next = arcs[cheapest[next]].source;
+            }
+
+            cycles.push(cycle);
+        }
+    }
+
+    cycles
+}
@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{stable_graph::StableGraph, unstable_graph::EdgeIndex, Cyclic};
+
+#[test]
+fn contracts_cycles() {
+    let mut graph = UnstableGraph::<(), i32, Directed, Cyclic>::directed();
+    let v = (0..4)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    // The cheapest edges into 1, 2 and 3 form a cycle, which is best entered at 2.
+    let edges = [
+        (0, 1, 10),
+        (0, 2, 6),
+        (1, 2, 1),
+        (2, 3, 1),
+        (3, 1, 1),
+        (0, 3, 20),
+    ];
+    for (a, b, w) in edges {
+        graph.insert_edge(v[a], v[b], w).unwrap();
+    }
+
+    let mut chosen = minimum_spanning_arborescence(&graph, v[0], |&w| w).unwrap();
+    chosen.sort();
+    assert_eq!(chosen, [1, 3, 4].map(EdgeIndex::new));
+
+    let tree = minimum_spanning_arborescence_graph(&graph, v[0], |&w| w).unwrap();
+    assert_eq!(tree.vertex_count(), 4);
+    assert_eq!(tree.edges.iter().map(|edge| edge.weight).sum::<i32>(), 8);
+
+    assert!(minimum_spanning_arborescence(&graph, v[1], |&w| w).is_none());
+}
+
+#[test]
+fn contracts_nested_cycles_in_stable_graph() {
+    let mut graph = StableGraph::<(), f64, _, Cyclic>::new_directed();
+    let v = (0..6).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    let edges = [
+        (0, 1, 5.0),
+        (1, 2, 1.0),
+        (2, 1, 1.0),
+        (2, 3, 2.0),
+        (3, 4, 1.0),
+        (4, 3, 1.0),
+        (4, 2, 0.5),
+        (0, 5, 3.0),
+        (5, 4, 2.5),
+    ];
+    let keys = edges
+        .iter()
+        .map(|&(a, b, w)| graph.insert_edge(v[a], v[b], w).unwrap())
+        .collect::<Vec<_>>();
+
+    let chosen = minimum_spanning_arborescence(&graph, v[0], |&w| w).unwrap();
+    let total = chosen
+        .iter()
+        .map(|&key| graph.edges[key].weight)
+        .sum::<f64>();
+
+    assert_eq!(chosen.len(), 5);
+    assert_eq!(total, 8.0);
+    assert!(chosen.contains(&keys[7]) && chosen.contains(&keys[8]));
+}
@@ -0,0 +1,91 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+use std::collections::{HashSet, VecDeque};
+
+/// A two-colouring of a graph's vertices such that every edge joins a vertex in
+/// [`left`][`Self::left`] to a vertex in [`right`][`Self::right`].
+#[derive(Clone, Debug)]
+pub struct Bipartition<N> {
+    pub left: HashSet<N>,
+    pub right: HashSet<N>,
+}
+
+/// A cycle of odd length, proving that a graph is not bipartite. The cycle is given as the
+/// sequence of vertices along it; the last vertex is adjacent to the first.
+#[derive(Clone, Debug)]
+pub struct OddCycle<N>(pub Vec<N>);
+
+/// Finds a [`Bipartition`] of `graph`'s vertices, or an [`OddCycle`] witnessing that none exists.
+/// Edge direction is ignored.
+pub fn is_bipartite<G: Graph + ?Sized>(
+    graph: &G,
+) -> Result<Bipartition<G::VertexId>, OddCycle<G::VertexId>> {
+    let indexed = Indexed::new(graph);
+    let count = indexed.vertex_count();
+
+    let mut depth = vec![usize::MAX; count];
+    let mut parent = vec![usize::MAX; count];
+    let mut queue = VecDeque::new();
+
+    for root in 0..count {
+        if depth[root] != usize::MAX {
+            continue;
+        }
+
+        depth[root] = 0;
+        queue.push_back(root);
+
+        while let Some(vertex) = queue.pop_front() {
+            for &(neighbour, _) in &indexed.adjacent[vertex] {
+                if depth[neighbour] == usize::MAX {
+                    depth[neighbour] = depth[vertex] + 1;
+                    parent[neighbour] = vertex;
+                    queue.push_back(neighbour);
+                } else if depth[neighbour] % 2 == depth[vertex] % 2 {
+                    let cycle = odd_cycle(&depth, &parent, vertex, neighbour);
+
+                    return Err(OddCycle(
+                        cycle.into_iter().map(|v| indexed.vertices[v]).collect(),
+                    ));
+                }
+            }
+        }
+    }
+
+    let (left, right) = (0..count).partition::<Vec<_>, _>(|&vertex| depth[vertex] % 2 == 0);
+
+    Ok(Bipartition {
+        left: left.into_iter().map(|v| indexed.vertices[v]).collect(),
+        right: right.into_iter().map(|v| indexed.vertices[v]).collect(),
+    })
+}
+
+/// Closes the odd cycle formed by the breadth-first tree paths to `a` and `b`, which are adjacent
+/// and at depths of equal parity.
+fn odd_cycle(depth: &[usize], parent: &[usize], mut a: usize, mut b: usize) -> Vec<usize> {
+    let mut from_a = vec![];
+    let mut from_b = vec![];
+
+    while depth[a] > depth[b] {
+        from_a.push(a);
+        a = parent[a];
+    }
+
+    while depth[b] > depth[a] {
+        from_b.push(b);
+        b = parent[b];
+    }
+
+    while a != b {
+        from_a.push(a);
+        from_b.push(b);
+        a = parent[a];
+        b = parent[b];
+    }
+
+    from_a.push(a);
+    from_a.extend(from_b.into_iter().rev());
+    from_a
+}
@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{stable_graph::StableGraph, unstable_graph::*, Cyclic, Undirected};
+
+#[test]
+fn even_cycle_is_bipartite() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let vertices = (0..6)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    for i in 0..6 {
+        graph
+            .insert_edge(vertices[i], vertices[(i + 1) % 6], ())
+            .unwrap();
+    }
+
+    let bipartition = is_bipartite(&graph).unwrap();
+
+    assert_eq!(bipartition.left.len(), 3);
+    assert_eq!(bipartition.right.len(), 3);
+
+    for edge in &graph.edges {
+        let (a, b) = edge.vertex_indices();
+        assert_ne!(bipartition.left.contains(&a), bipartition.left.contains(&b));
+    }
+}
+
+#[test]
+fn odd_cycle_is_witnessed() {
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let vertices = (0..7).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    for i in 0..5 {
+        graph
+            .insert_edge(vertices[i], vertices[(i + 1) % 5], ())
+            .unwrap();
+    }
+    graph.insert_edge(vertices[5], vertices[6], ()).unwrap();
+
+    let OddCycle(cycle) = is_bipartite(&graph).unwrap_err();
+
+    assert_eq!(cycle.len(), 5);
+
+    for (i, a) in cycle.iter().enumerate() {
+        let b = cycle[(i + 1) % cycle.len()];
+        assert!(graph
+            .edges
+            .values()
+            .any(|edge| { edge.vertices == [*a, b] || edge.vertices == [b, *a] }));
+    }
+}
@@ -0,0 +1,288 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+use std::collections::{HashMap, VecDeque};
+
+/// The total change in scores below which an iterative measure is considered converged.
+const TOLERANCE: f64 = 1e-10;
+/// The number of iterations after which an iterative measure gives up.
+const MAX_ITERATIONS: usize = 1000;
+
+/// Computes the [PageRank](https://en.wikipedia.org/wiki/PageRank) of every vertex of `graph`,
+/// the probability that a random walker who follows an edge with probability `damping` and
+/// otherwise teleports ends up at the vertex. Undirected edges can be followed either way.
+///
+/// Teleports, and steps from vertices without outgoing edges, land on a vertex chosen uniformly
+/// or, if `personalization` is given, in proportion to its value there. The scores sum to `1`.
+pub fn page_rank<G: Graph + ?Sized>(
+    graph: &G,
+    damping: f64,
+    personalization: Option<&HashMap<G::VertexId, f64>>,
+) -> HashMap<G::VertexId, f64> {
+    let indexed = Indexed::new(graph);
+    let count = indexed.vertex_count();
+
+    let mut teleport = match personalization {
+        Some(personalization) => indexed
+            .vertices
+            .iter()
+            .map(|vertex| personalization.get(vertex).copied().unwrap_or(0.0))
+            .collect(),
+        None => vec![1.0; count],
+    };
+
+    let total = teleport.iter().sum::<f64>();
+    match total > 0.0 {
+        true => teleport.iter_mut().for_each(|p| *p /= total),
+        false => teleport.fill(1.0 / count as f64),
+    }
+
+    let mut rank = teleport.clone();
+
+    for _ in 0..MAX_ITERATIONS {
+        let dangling = (0..count)
+            .filter(|&v| indexed.outgoing[v].is_empty())
+            .map(|v| rank[v])
+            .sum::<f64>();
+
+        let mut next = teleport
+            .iter()
+            .map(|&p| (1.0 - damping + damping * dangling) * p)
+            .collect::<Vec<_>>();
+
+        for (vertex, outgoing) in indexed.outgoing.iter().enumerate() {
+            let share = damping * rank[vertex] / outgoing.len() as f64;
+
+            for &(neighbour, _) in outgoing {
+                next[neighbour] += share;
+            }
+        }
+
+        let done = converged(&rank, &next);
+        rank = next;
+
+        if done {
+            break;
+        }
+    }
+
+    collect(&indexed, rank)
+}
+
+/// Computes the betweenness centrality of every vertex of `graph`: the number of shortest paths
+/// between other pairs of vertices that pass through it, with paths shared by ties split evenly.
+/// Uses [Brandes' algorithm](https://doi.org/10.1080/0022250X.2001.9990249).
+///
+/// If `normalized` is `true`, scores are divided by the number of ordered pairs of other
+/// vertices. Otherwise, each undirected path is counted once rather than once per direction.
+pub fn betweenness_centrality<G: Graph + ?Sized>(
+    graph: &G,
+    normalized: bool,
+) -> HashMap<G::VertexId, f64> {
+    let indexed = Indexed::new(graph);
+    let n = indexed.vertex_count() as f64;
+    let (mut vertices, _) = brandes(&indexed);
+
+    let scale = match normalized {
+        true if n > 2.0 => 1.0 / ((n - 1.0) * (n - 2.0)),
+        true => 1.0,
+        false if indexed.directed => 1.0,
+        false => 0.5,
+    };
+
+    vertices.iter_mut().for_each(|score| *score *= scale);
+    collect(&indexed, vertices)
+}
+
+/// Computes the betweenness centrality of every edge of `graph`: the number of shortest paths
+/// between pairs of vertices that use it, with paths shared by ties split evenly.
+///
+/// If `normalized` is `true`, scores are divided by the number of ordered pairs of vertices.
+/// Otherwise, each undirected path is counted once rather than once per direction.
+pub fn edge_betweenness_centrality<G: Graph + ?Sized>(
+    graph: &G,
+    normalized: bool,
+) -> HashMap<G::EdgeId, f64> {
+    let indexed = Indexed::new(graph);
+    let n = indexed.vertex_count() as f64;
+    let (_, mut edges) = brandes(&indexed);
+
+    let scale = match normalized {
+        true if n > 1.0 => 1.0 / (n * (n - 1.0)),
+        true => 1.0,
+        false if indexed.directed => 1.0,
+        false => 0.5,
+    };
+
+    edges.iter_mut().for_each(|score| *score *= scale);
+
+    indexed
+        .edges
+        .iter()
+        .map(|edge| edge.id)
+        .zip(edges)
+        .collect()
+}
+
+/// Computes the closeness centrality of every vertex of `graph`: the reciprocal of the average
+/// distance from the vertex to the vertices it can reach, scaled by the fraction of other vertices
+/// it can reach so that vertices in small components do not score highly.
+pub fn closeness_centrality<G: Graph + ?Sized>(graph: &G) -> HashMap<G::VertexId, f64> {
+    let indexed = Indexed::new(graph);
+    let count = indexed.vertex_count();
+
+    let closeness = (0..count)
+        .map(|source| {
+            let distances = distances_from(&indexed, source);
+            let reached = distances.iter().filter(|&&d| d != usize::MAX).count() - 1;
+            let total = distances
+                .iter()
+                .filter(|&&d| d != usize::MAX)
+                .sum::<usize>();
+
+            match total {
+                0 => 0.0,
+                _ => (reached as f64 / total as f64) * (reached as f64 / (count - 1) as f64),
+            }
+        })
+        .collect();
+
+    collect(&indexed, closeness)
+}
+
+/// Computes the [eigenvector centrality](https://en.wikipedia.org/wiki/Eigenvector_centrality) of
+/// every vertex of `graph`, in which a vertex is central if the vertices with edges into it are.
+/// The scores have a Euclidean norm of `1`.
+///
+/// Returns [`None`] if the power iteration used to find the scores does not converge.
+pub fn eigenvector_centrality<G: Graph + ?Sized>(graph: &G) -> Option<HashMap<G::VertexId, f64>> {
+    let indexed = Indexed::new(graph);
+    let count = indexed.vertex_count();
+    let mut scores = vec![1.0 / count as f64; count];
+
+    if count == 0 {
+        return Some(HashMap::new());
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        // Iterating with `A + I` rather than `A` keeps bipartite graphs from oscillating.
+        let mut next = scores.clone();
+
+        for edge in &indexed.edges {
+            next[edge.target] += scores[edge.source];
+
+            if !indexed.directed && edge.source != edge.target {
+                next[edge.source] += scores[edge.target];
+            }
+        }
+
+        let norm = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return None;
+        }
+        next.iter_mut().for_each(|x| *x /= norm);
+
+        let done = converged(&scores, &next);
+        scores = next;
+
+        if done {
+            return Some(collect(&indexed, scores));
+        }
+    }
+
+    None
+}
+
+/// Accumulates the pair dependencies of every vertex and every edge over all sources.
+fn brandes<G: Graph + ?Sized>(indexed: &Indexed<'_, G>) -> (Vec<f64>, Vec<f64>) {
+    let count = indexed.vertex_count();
+    let mut vertices = vec![0.0; count];
+    let mut edges = vec![0.0; indexed.edges.len()];
+
+    let mut order = Vec::with_capacity(count);
+    let mut predecessors = vec![Vec::<(usize, usize)>::new(); count];
+    let mut paths = vec![0.0; count];
+    let mut distance = vec![usize::MAX; count];
+    let mut dependency = vec![0.0; count];
+    let mut queue = VecDeque::new();
+
+    for source in 0..count {
+        order.clear();
+        predecessors.iter_mut().for_each(Vec::clear);
+        paths.fill(0.0);
+        distance.fill(usize::MAX);
+        dependency.fill(0.0);
+
+        paths[source] = 1.0;
+        distance[source] = 0;
+        queue.push_back(source);
+
+        while let Some(vertex) = queue.pop_front() {
+            order.push(vertex);
+
+            for &(neighbour, edge) in &indexed.outgoing[vertex] {
+                if distance[neighbour] == usize::MAX {
+                    distance[neighbour] = distance[vertex] + 1;
+                    queue.push_back(neighbour);
+                }
+
+                if distance[neighbour] == distance[vertex] + 1 {
+                    paths[neighbour] += paths[vertex];
+                    predecessors[neighbour].push((vertex, edge));
+                }
+            }
+        }
+
+        while let Some(vertex) = order.pop() {
+            for &(predecessor, edge) in &predecessors[vertex] {
+                let credit = paths[predecessor] / paths[vertex] * (1.0 + dependency[vertex]);
+                dependency[predecessor] += credit;
+                edges[edge] += credit;
+            }
+
+            if vertex != source {
+                vertices[vertex] += dependency[vertex];
+            }
+        }
+    }
+
+    (vertices, edges)
+}
+
+/// Returns the number of edges on a shortest path from `source` to every vertex, or
+/// [`usize::MAX`] if there is none.
+fn distances_from<G: Graph + ?Sized>(indexed: &Indexed<'_, G>, source: usize) -> Vec<usize> {
+    let mut distance = vec![usize::MAX; indexed.vertex_count()];
+    let mut queue = VecDeque::from([source]);
+    distance[source] = 0;
+
+    while let Some(vertex) = queue.pop_front() {
+        for &(neighbour, _) in &indexed.outgoing[vertex] {
+            if distance[neighbour] == usize::MAX {
+                distance[neighbour] = distance[vertex] + 1;
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    distance
+}
+
+#[inline]
+fn converged(previous: &[f64], next: &[f64]) -> bool {
+    previous
+        .iter()
+        .zip(next)
+        .map(|(a, b)| (a - b).abs())
+        .sum::<f64>()
+        < TOLERANCE
+}
+
+#[inline]
+fn collect<G: Graph + ?Sized>(
+    indexed: &Indexed<'_, G>,
+    scores: Vec<f64>,
+) -> HashMap<G::VertexId, f64> {
+    indexed.vertices.iter().copied().zip(scores).collect()
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{stable_graph::StableGraph, unstable_graph::*, Cyclic, Directed, Undirected};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+#[test]
+fn path_centralities() {
+    // 0 - 1 - 2 - 3 - 4
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = (0..5)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+    for i in 0..4 {
+        graph.insert_edge(v[i], v[i + 1], ()).unwrap();
+    }
+
+    let betweenness = betweenness_centrality(&graph, false);
+    assert!(close(betweenness[&v[0]], 0.0));
+    assert!(close(betweenness[&v[1]], 3.0));
+    assert!(close(betweenness[&v[2]], 4.0));
+
+    let normalized = betweenness_centrality(&graph, true);
+    assert!(close(normalized[&v[2]], 4.0 / 6.0));
+
+    let edges = edge_betweenness_centrality(&graph, false);
+    assert!(close(edges[&EdgeIndex::new(0)], 4.0));
+    assert!(close(edges[&EdgeIndex::new(1)], 6.0));
+
+    let closeness = closeness_centrality(&graph);
+    assert!(close(closeness[&v[2]], 4.0 / 6.0));
+    assert!(close(closeness[&v[0]], 4.0 / 10.0));
+
+    let eigenvector = eigenvector_centrality(&graph).unwrap();
+    assert!(eigenvector[&v[2]] > eigenvector[&v[1]] && eigenvector[&v[1]] > eigenvector[&v[0]]);
+    assert!(close(eigenvector.values().map(|x| x * x).sum(), 1.0));
+}
+
+#[test]
+fn page_rank_follows_edges() {
+    let mut graph = StableGraph::<(), (), _, Cyclic>::new_directed();
+    let v = (0..4).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    // Everything points at `v[3]`, which points nowhere.
+    for i in 0..3 {
+        graph.insert_edge(v[i], v[3], ()).unwrap();
+    }
+    graph.insert_edge(v[0], v[1], ()).unwrap();
+
+    let ranks = page_rank(&graph, 0.85, None);
+    assert!(close(ranks.values().sum(), 1.0));
+    assert!(ranks[&v[3]] > ranks[&v[1]] && ranks[&v[1]] > ranks[&v[0]]);
+    assert!(close(ranks[&v[0]], ranks[&v[2]]));
+
+    let personalization = HashMap::from([(v[2], 1.0)]);
+    let personal = page_rank(&graph, 0.85, Some(&personalization));
+    assert!(close(personal.values().sum(), 1.0));
+    assert!(close(personal[&v[0]], 0.0));
+    assert!(personal[&v[2]] > personal[&v[1]]);
+}
+
+#[test]
+fn directed_cycle_is_symmetric() {
+    let mut graph = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let v = (0..4)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+    for i in 0..4 {
+        graph.insert_edge(v[i], v[(i + 1) % 4], ()).unwrap();
+    }
+
+    let ranks = page_rank(&graph, 0.85, None);
+    let betweenness = betweenness_centrality(&graph, false);
+
+    for i in 0..4 {
+        assert!(close(ranks[&v[i]], 0.25));
+        // Each vertex lies on the paths from its predecessor to the two vertices after it, and
+        // on the path from the vertex two behind it to its successor.
+        assert!(close(betweenness[&v[i]], 3.0));
+    }
+}
@@ -0,0 +1,131 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+
+/// An iterator over the maximal cliques of a graph, created by [`maximal_cliques`].
+pub struct MaximalCliques<N> {
+    vertices: Vec<N>,
+    neighbours: Vec<Vec<usize>>,
+    stack: Vec<Frame>,
+}
+
+/// A call of the Bron–Kerbosch recursion: the clique built so far, the vertices that may extend
+/// it, the vertices that already have, and the extensions still to be tried.
+struct Frame {
+    clique: Vec<usize>,
+    candidates: Vec<usize>,
+    excluded: Vec<usize>,
+    pending: Vec<usize>,
+}
+
+/// Returns an iterator over the maximal cliques of `graph`, that is the sets of pairwise adjacent
+/// vertices that cannot be extended by another vertex, using the
+/// [Bron–Kerbosch](https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm) algorithm with
+/// pivoting. Edge direction and self-loops are ignored.
+pub fn maximal_cliques<G: Graph + ?Sized>(graph: &G) -> MaximalCliques<G::VertexId> {
+    let indexed = Indexed::new(graph);
+
+    let mut cliques = MaximalCliques {
+        neighbours: indexed.neighbour_sets(),
+        vertices: indexed.vertices,
+        stack: vec![],
+    };
+
+    if !cliques.vertices.is_empty() {
+        let candidates = (0..cliques.vertices.len()).collect::<Vec<_>>();
+        let pending = cliques.pivot_complement(&candidates, &[]);
+
+        cliques.stack.push(Frame {
+            clique: vec![],
+            candidates,
+            excluded: vec![],
+            pending,
+        });
+    }
+
+    cliques
+}
+
+/// Returns a largest clique of `graph`, or an empty [`Vec`] if it has no vertices. Edge
+/// direction and self-loops are ignored.
+pub fn maximum_clique<G: Graph + ?Sized>(graph: &G) -> Vec<G::VertexId> {
+    maximal_cliques(graph)
+        .max_by_key(Vec::len)
+        .unwrap_or_default()
+}
+
+impl<N> MaximalCliques<N> {
+    /// Chooses the vertex of `candidates` or `excluded` with the most neighbours in `candidates`
+    /// and returns the candidates that are not its neighbours, since every maximal clique
+    /// extending the current one contains the pivot or one of its non-neighbours.
+    fn pivot_complement(&self, candidates: &[usize], excluded: &[usize]) -> Vec<usize> {
+        let adjacent = |a: usize, b: usize| self.neighbours[a].binary_search(&b).is_ok();
+
+        let pivot = candidates
+            .iter()
+            .chain(excluded)
+            .copied()
+            .max_by_key(|&pivot| {
+                candidates
+                    .iter()
+                    .filter(|&&candidate| adjacent(pivot, candidate))
+                    .count()
+            })
+            .unwrap();
+
+        candidates
+            .iter()
+            .copied()
+            .filter(|&candidate| !adjacent(pivot, candidate))
+            .collect()
+    }
+}
+
+impl<N: Copy> Iterator for MaximalCliques<N> {
+    type Item = Vec<N>;
+
+    /// Gets the next item of the iterator.
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(frame) = self.stack.last_mut() {
+            let Some(vertex) = frame.pending.pop() else {
+                self.stack.pop();
+                continue;
+            };
+
+            let neighbours = &self.neighbours[vertex];
+            let within = |set: &[usize]| {
+                set.iter()
+                    .copied()
+                    .filter(|other| neighbours.binary_search(other).is_ok())
+                    .collect::<Vec<_>>()
+            };
+
+            let candidates = within(&frame.candidates);
+            let excluded = within(&frame.excluded);
+            let mut clique = frame.clique.clone();
+            clique.push(vertex);
+
+            frame.candidates.retain(|&candidate| candidate != vertex);
+            frame.excluded.push(vertex);
+
+            if candidates.is_empty() {
+                if excluded.is_empty() {
+                    return Some(clique.into_iter().map(|v| self.vertices[v]).collect());
+                }
+                continue;
+            }
+
+            let pending = self.pivot_complement(&candidates, &excluded);
+
+            self.stack.push(Frame {
+                clique,
+                candidates,
+                excluded,
+                pending,
+            });
+        }
+
+        None
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{stable_graph::StableGraph, unstable_graph::*, Cyclic, Undirected};
+
+#[test]
+fn enumerates_maximal_cliques() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = (0..7)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    // A 4-clique sharing a vertex with a triangle, a self-loop on `v[5]`, an edge parallel to the
+    // one between `v[0]` and `v[1]`, and an isolated vertex.
+    for (a, b) in [
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (5, 5),
+    ] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+    graph.insert_edge(v[1], v[0], ()).unwrap();
+
+    let mut cliques = maximal_cliques(&graph)
+        .map(|mut clique| {
+            clique.sort();
+            clique
+        })
+        .collect::<Vec<_>>();
+    cliques.sort();
+
+    assert_eq!(
+        cliques,
+        vec![
+            vec![v[0], v[1], v[2], v[3]],
+            vec![v[3], v[4], v[5]],
+            vec![v[6]]
+        ]
+    );
+}
+
+#[test]
+fn finds_maximum_clique_in_stable_graph() {
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let v = (0..6).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    for (a, b) in [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 0),
+        (1, 3),
+        (0, 2),
+        (3, 4),
+        (4, 5),
+    ] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    let mut clique = maximum_clique(&graph);
+    clique.sort();
+    let mut expected = v[..4].to_vec();
+    expected.sort();
+
+    assert_eq!(clique, expected);
+}
@@ -0,0 +1,86 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+use std::collections::HashMap;
+
+/// Returns the number of triangles that each vertex of `graph` is part of. Edge direction,
+/// parallel edges and self-loops are ignored.
+pub fn triangles<G: Graph + ?Sized>(graph: &G) -> HashMap<G::VertexId, usize> {
+    let indexed = Indexed::new(graph);
+    let counts = count_triangles(&indexed.neighbour_sets());
+
+    indexed.vertices.iter().copied().zip(counts).collect()
+}
+
+/// Returns the number of triangles in `graph`. Edge direction, parallel edges and self-loops are
+/// ignored.
+pub fn triangle_count<G: Graph + ?Sized>(graph: &G) -> usize {
+    count_triangles(&Indexed::new(graph).neighbour_sets())
+        .into_iter()
+        .sum::<usize>()
+        / 3
+}
+
+/// Returns the local clustering coefficient of each vertex of `graph`: the fraction of pairs of
+/// its neighbours that are themselves adjacent, or `0` for vertices with fewer than two
+/// neighbours. Edge direction, parallel edges and self-loops are ignored.
+pub fn clustering_coefficients<G: Graph + ?Sized>(graph: &G) -> HashMap<G::VertexId, f64> {
+    let indexed = Indexed::new(graph);
+    let neighbours = indexed.neighbour_sets();
+
+    let coefficients = count_triangles(&neighbours)
+        .into_iter()
+        .zip(&neighbours)
+        .map(|(triangles, neighbours)| match neighbours.len() {
+            0 | 1 => 0.0,
+            degree => 2.0 * triangles as f64 / (degree * (degree - 1)) as f64,
+        });
+
+    indexed.vertices.iter().copied().zip(coefficients).collect()
+}
+
+/// Returns the mean of the local clustering coefficients of `graph`'s vertices, or `0` if it has
+/// none.
+pub fn average_clustering<G: Graph + ?Sized>(graph: &G) -> f64 {
+    let coefficients = clustering_coefficients(graph);
+
+    match coefficients.len() {
+        0 => 0.0,
+        count => coefficients.values().sum::<f64>() / count as f64,
+    }
+}
+
+/// Counts the triangles at each vertex by finding, for every edge `(a, b)` with `a < b`, the
+/// common neighbours above `b`, so that each triangle is found once.
+fn count_triangles(neighbours: &[Vec<usize>]) -> Vec<usize> {
+    let mut counts = vec![0; neighbours.len()];
+
+    for (a, adjacent) in neighbours.iter().enumerate() {
+        for &b in adjacent.iter().filter(|&&b| b > a) {
+            let (mut x, mut y) = (adjacent.iter().peekable(), neighbours[b].iter().peekable());
+
+            while let (Some(&&p), Some(&&q)) = (x.peek(), y.peek()) {
+                match p.cmp(&q) {
+                    std::cmp::Ordering::Less => {
+                        x.next();
+                    }
+                    std::cmp::Ordering::Greater => {
+                        y.next();
+                    }
+                    std::cmp::Ordering::Equal => {
+                        if p > b {
+                            counts[a] += 1;
+                            counts[b] += 1;
+                            counts[p] += 1;
+                        }
+                        x.next();
+                        y.next();
+                    }
+                }
+            }
+        }
+    }
+
+    counts
+}
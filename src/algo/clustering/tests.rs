@@ -0,0 +1,43 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{unstable_graph::*, Cyclic, Undirected};
+
+#[test]
+fn counts_triangles_and_clustering() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = (0..5)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    // Two triangles sharing the edge (1, 2), with a pendant vertex, a parallel edge and a
+    // self-loop that should not count.
+    for (a, b) in [
+        (0, 1),
+        (0, 2),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (3, 4),
+        (1, 2),
+        (4, 4),
+    ] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    let triangles = triangles(&graph);
+    assert_eq!(triangles[&v[0]], 1);
+    assert_eq!(triangles[&v[1]], 2);
+    assert_eq!(triangles[&v[3]], 1);
+    assert_eq!(triangles[&v[4]], 0);
+    assert_eq!(triangle_count(&graph), 2);
+
+    let clustering = clustering_coefficients(&graph);
+    assert_eq!(clustering[&v[0]], 1.0);
+    assert_eq!(clustering[&v[1]], 2.0 / 3.0);
+    assert_eq!(clustering[&v[3]], 1.0 / 3.0);
+    assert_eq!(clustering[&v[4]], 0.0);
+
+    let average = (1.0 + 2.0 / 3.0 + 2.0 / 3.0 + 1.0 / 3.0) / 5.0;
+    assert!((average_clustering(&graph) - average).abs() < 1e-12);
+}
@@ -0,0 +1,178 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+use std::collections::HashMap;
+
+const UNCOLOURED: usize = usize::MAX;
+
+/// The result of [`chaitin_briggs`]: a colour for every vertex that could be given one, and the
+/// vertices that must be spilled.
+#[derive(Clone, Debug)]
+pub struct Allocation<N> {
+    pub colours: HashMap<N, usize>,
+    pub spilled: Vec<N>,
+}
+
+/// Colours `graph` greedily, giving each vertex in `order` the smallest colour that none of its
+/// coloured neighbours have. Vertices missing from `order` are coloured afterwards in the graph's
+/// own order. Colours are numbered from `0`; edge direction and self-loops are ignored.
+pub fn greedy_colouring<G, I>(graph: &G, order: I) -> HashMap<G::VertexId, usize>
+where
+    G: Graph + ?Sized,
+    I: IntoIterator<Item = G::VertexId>,
+{
+    let indexed = Indexed::new(graph);
+    let neighbours = indexed.neighbour_sets();
+    let mut colours = vec![UNCOLOURED; indexed.vertex_count()];
+
+    let order = order
+        .into_iter()
+        .filter_map(|vertex| indexed.positions.get(&vertex).copied())
+        .chain(0..indexed.vertex_count());
+
+    for vertex in order {
+        if colours[vertex] == UNCOLOURED {
+            colours[vertex] = smallest_free(&colours, &neighbours[vertex], usize::MAX).unwrap();
+        }
+    }
+
+    indexed.vertices.iter().copied().zip(colours).collect()
+}
+
+/// Colours `graph` with Brélaz's [DSatur](https://en.wikipedia.org/wiki/DSatur) heuristic, which
+/// repeatedly colours the vertex whose neighbours already use the most distinct colours, breaking
+/// ties by degree. Colours are numbered from `0`; edge direction and self-loops are ignored.
+pub fn dsatur<G: Graph + ?Sized>(graph: &G) -> HashMap<G::VertexId, usize> {
+    let indexed = Indexed::new(graph);
+    let neighbours = indexed.neighbour_sets();
+    let count = indexed.vertex_count();
+
+    let mut colours = vec![UNCOLOURED; count];
+    let mut neighbour_colours = vec![Vec::<usize>::new(); count];
+
+    for _ in 0..count {
+        let vertex = (0..count)
+            .filter(|&vertex| colours[vertex] == UNCOLOURED)
+            .max_by_key(|&vertex| {
+                (
+                    neighbour_colours[vertex].len(),
+                    neighbours[vertex].len(),
+                    usize::MAX - vertex,
+                )
+            })
+            .unwrap();
+
+        let colour = smallest_free(&colours, &neighbours[vertex], usize::MAX).unwrap();
+        colours[vertex] = colour;
+
+        for &neighbour in &neighbours[vertex] {
+            if let Err(at) = neighbour_colours[neighbour].binary_search(&colour) {
+                neighbour_colours[neighbour].insert(at, colour);
+            }
+        }
+    }
+
+    indexed.vertices.iter().copied().zip(colours).collect()
+}
+
+/// Colours the interference graph `graph` with at most `k` colours in the manner of a
+/// Chaitin–Briggs register allocator, reporting the vertices that could not be coloured.
+///
+/// Vertices with fewer than `k` neighbours are removed one at a time, since they can always be
+/// coloured once the rest of the graph is. When none remain, the vertex with the lowest `cost` per
+/// neighbour is removed as a spill candidate. Vertices are then coloured in reverse order of
+/// removal; following Briggs, a spill candidate is only spilled if its neighbours have used up all
+/// `k` colours by the time it is reached. Edge direction and self-loops are ignored.
+pub fn chaitin_briggs<G, F>(graph: &G, k: usize, mut cost: F) -> Allocation<G::VertexId>
+where
+    G: Graph + ?Sized,
+    F: FnMut(&G::VertexWeight) -> f64,
+{
+    let indexed = Indexed::new(graph);
+    let neighbours = indexed.neighbour_sets();
+    let count = indexed.vertex_count();
+
+    let costs = indexed
+        .vertex_weights
+        .iter()
+        .map(|weight| cost(weight))
+        .collect::<Vec<_>>();
+
+    let mut degree = neighbours.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut removed = vec![false; count];
+    let mut stack = Vec::with_capacity(count);
+    let mut low_degree = (0..count)
+        .rev()
+        .filter(|&v| degree[v] < k)
+        .collect::<Vec<_>>();
+
+    while stack.len() < count {
+        let vertex = match low_degree.pop() {
+            Some(vertex) if removed[vertex] => continue,
+            Some(vertex) => vertex,
+            None => (0..count)
+                .filter(|&vertex| !removed[vertex])
+                .min_by(|&a, &b| {
+                    let a = costs[a] / degree[a] as f64;
+                    let b = costs[b] / degree[b] as f64;
+                    a.total_cmp(&b)
+                })
+                .unwrap(),
+        };
+
+        removed[vertex] = true;
+        stack.push(vertex);
+
+        for &neighbour in &neighbours[vertex] {
+            if !removed[neighbour] {
+                degree[neighbour] -= 1;
+
+                if degree[neighbour] + 1 == k {
+                    low_degree.push(neighbour);
+                }
+            }
+        }
+    }
+
+    let mut colours = vec![UNCOLOURED; count];
+    let mut spilled = vec![];
+
+    while let Some(vertex) = stack.pop() {
+        match smallest_free(&colours, &neighbours[vertex], k) {
+            Some(colour) => colours[vertex] = colour,
+            None => spilled.push(indexed.vertices[vertex]),
+        }
+    }
+
+    Allocation {
+        colours: indexed
+            .vertices
+            .iter()
+            .copied()
+            .zip(colours)
+            .filter(|&(_, colour)| colour != UNCOLOURED)
+            .collect(),
+        spilled,
+    }
+}
+
+/// Returns the smallest colour below `limit` that none of `neighbours` have.
+fn smallest_free(colours: &[usize], neighbours: &[usize], limit: usize) -> Option<usize> {
+    let mut used = neighbours
+        .iter()
+        .map(|&neighbour| colours[neighbour])
+        .filter(|&colour| colour != UNCOLOURED)
+        .collect::<Vec<_>>();
+
+    used.sort_unstable();
+    used.dedup();
+
+    let free = used
+        .iter()
+        .enumerate()
+        .find(|&(colour, &used)| colour != used)
+        .map_or(used.len(), |(colour, _)| colour);
+
+    (free < limit).then_some(free)
+}
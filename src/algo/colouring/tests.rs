@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{unstable_graph::*, Cyclic, Undirected};
+
+fn wheel(spokes: usize) -> UnstableGraph<f64, (), Undirected, Cyclic> {
+    let mut graph = UnstableGraph::<_, _, Undirected, Cyclic>::undirected();
+    let hub = graph.insert_vertex(100.0).unwrap();
+    let rim = (0..spokes)
+        .map(|i| graph.insert_vertex(i as f64 + 1.0).unwrap())
+        .collect::<Vec<_>>();
+
+    for i in 0..spokes {
+        graph.insert_edge(hub, rim[i], ()).unwrap();
+        graph
+            .insert_edge(rim[i], rim[(i + 1) % spokes], ())
+            .unwrap();
+    }
+
+    graph
+}
+
+fn assert_proper(
+    graph: &UnstableGraph<f64, (), Undirected, Cyclic>,
+    colours: &HashMap<VertexIndex, usize>,
+) {
+    for edge in &graph.edges {
+        let (a, b) = edge.vertex_indices();
+        if let (Some(a), Some(b)) = (colours.get(&a), colours.get(&b)) {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn colourings_are_proper() {
+    let graph = wheel(5);
+
+    let greedy = greedy_colouring(&graph, [3, 1, 4].map(VertexIndex::new));
+    assert_eq!(greedy.len(), 6);
+    assert_proper(&graph, &greedy);
+
+    // A wheel with an odd rim needs exactly four colours, which DSatur finds.
+    let dsatur = dsatur(&graph);
+    assert_proper(&graph, &dsatur);
+    assert_eq!(dsatur.values().max(), Some(&3));
+}
+
+#[test]
+fn chaitin_briggs_spills_cheapest() {
+    let graph = wheel(5);
+
+    let allocation = chaitin_briggs(&graph, 4, |&cost| cost);
+    assert!(allocation.spilled.is_empty());
+    assert_proper(&graph, &allocation.colours);
+
+    // With three colours one rim vertex has to go, and the cheapest one is chosen.
+    let allocation = chaitin_briggs(&graph, 3, |&cost| cost);
+    assert_eq!(allocation.spilled, vec![VertexIndex::new(1)]);
+    assert_eq!(allocation.colours.len(), 5);
+    assert_proper(&graph, &allocation.colours);
+}
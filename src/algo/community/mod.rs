@@ -0,0 +1,337 @@
+mod tests;
+
+use super::{indexed::Indexed, rng::Rng};
+use crate::graph::traits::Graph;
+use std::collections::HashMap;
+
+/// The number of rounds after which label propagation stops even if labels are still changing.
+const MAX_ROUNDS: usize = 1000;
+/// The smallest modularity gain that is worth moving a vertex for.
+const MIN_GAIN: f64 = 1e-12;
+
+/// A division of a graph's vertices into communities, numbered from `0`.
+#[derive(Clone, Debug)]
+pub struct Communities<N> {
+    pub membership: HashMap<N, usize>,
+    /// The [modularity](https://en.wikipedia.org/wiki/Modularity_(networks)) of the division.
+    pub modularity: f64,
+}
+
+/// Divides `graph` into communities with the
+/// [Louvain method](https://en.wikipedia.org/wiki/Louvain_method), which greedily moves vertices
+/// between communities to raise modularity and then repeats the process on the graph of
+/// communities until nothing improves.
+///
+/// Edges are treated as undirected and weighted by `weight`. Vertices are visited in an order
+/// shuffled with `seed`, so the same seed always gives the same result.
+pub fn louvain<G, F>(graph: &G, weight: F, seed: u64) -> Communities<G::VertexId>
+where
+    G: Graph + ?Sized,
+    F: FnMut(&G::EdgeWeight) -> f64,
+{
+    let indexed = Indexed::new(graph);
+    let mut level = Level::new(&indexed, weight);
+    let original = level.clone();
+    let mut rng = Rng::new(seed);
+
+    let mut membership = (0..indexed.vertex_count()).collect::<Vec<_>>();
+
+    loop {
+        let communities = level.move_vertices(&mut rng);
+        let (communities, count) = renumber(&communities);
+
+        for community in &mut membership {
+            *community = communities[*community];
+        }
+
+        if count == level.len() {
+            break;
+        }
+
+        level = level.aggregate(&communities, count);
+    }
+
+    let (membership, _) = renumber(&membership);
+    let modularity = original.modularity(&membership);
+
+    Communities {
+        membership: indexed.vertices.iter().copied().zip(membership).collect(),
+        modularity,
+    }
+}
+
+/// Divides `graph` into communities with asynchronous
+/// [label propagation](https://en.wikipedia.org/wiki/Label_propagation_algorithm): every vertex
+/// starts with a label of its own and, in turn, adopts the label carrying the most edge weight
+/// among its neighbours until every vertex agrees with its neighbourhood.
+///
+/// Edges are treated as undirected and weighted by `weight`. The visiting order and tie-breaks
+/// are randomised with `seed`, so the same seed always gives the same result.
+pub fn label_propagation<G, F>(graph: &G, weight: F, seed: u64) -> Communities<G::VertexId>
+where
+    G: Graph + ?Sized,
+    F: FnMut(&G::EdgeWeight) -> f64,
+{
+    let indexed = Indexed::new(graph);
+    let level = Level::new(&indexed, weight);
+    let mut rng = Rng::new(seed);
+
+    let mut labels = (0..level.len()).collect::<Vec<_>>();
+    let mut order = labels.clone();
+
+    for _ in 0..MAX_ROUNDS {
+        rng.shuffle(&mut order);
+        let mut stable = true;
+
+        for &vertex in &order {
+            let mut weights = HashMap::<usize, f64>::new();
+            for &(neighbour, weight) in &level.neighbours[vertex] {
+                *weights.entry(labels[neighbour]).or_default() += weight;
+            }
+
+            let Some(best) = weights.values().copied().reduce(f64::max) else {
+                continue;
+            };
+
+            if weights.get(&labels[vertex]) == Some(&best) {
+                continue;
+            }
+
+            let mut ties = weights
+                .into_iter()
+                .filter(|&(_, weight)| weight == best)
+                .map(|(label, _)| label)
+                .collect::<Vec<_>>();
+            ties.sort_unstable();
+
+            labels[vertex] = ties[rng.below(ties.len())];
+            stable = false;
+        }
+
+        if stable {
+            break;
+        }
+    }
+
+    let (membership, _) = renumber(&labels);
+    let modularity = level.modularity(&membership);
+
+    Communities {
+        membership: indexed.vertices.iter().copied().zip(membership).collect(),
+        modularity,
+    }
+}
+
+/// Computes the modularity of dividing `graph` into the communities given by `membership`, with
+/// edges treated as undirected and weighted by `weight`. Vertices missing from `membership` are
+/// each taken to be in a community of their own.
+pub fn modularity<G, F>(graph: &G, membership: &HashMap<G::VertexId, usize>, weight: F) -> f64
+where
+    G: Graph + ?Sized,
+    F: FnMut(&G::EdgeWeight) -> f64,
+{
+    let indexed = Indexed::new(graph);
+    let level = Level::new(&indexed, weight);
+
+    let labels = indexed
+        .vertices
+        .iter()
+        .enumerate()
+        .map(|(index, vertex)| match membership.get(vertex) {
+            Some(&community) => community,
+            None => usize::MAX - index,
+        })
+        .collect::<Vec<_>>();
+
+    level.modularity(&renumber(&labels).0)
+}
+
+/// Renumbers arbitrary labels densely in order of first appearance, returning the new labels and
+/// how many there are.
+fn renumber(labels: &[usize]) -> (Vec<usize>, usize) {
+    let mut numbers = HashMap::new();
+
+    let labels = labels
+        .iter()
+        .map(|&label| {
+            let next = numbers.len();
+            *numbers.entry(label).or_insert(next)
+        })
+        .collect();
+
+    (labels, numbers.len())
+}
+
+/// A weighted undirected graph whose vertices are the communities of the level below.
+#[derive(Clone)]
+struct Level {
+    /// The neighbours of each vertex and the total weight of the edges to them, excluding
+    /// self-loops.
+    neighbours: Vec<Vec<(usize, f64)>>,
+    /// The total weight of each vertex's self-loops.
+    loops: Vec<f64>,
+    /// The weighted degree of each vertex, in which self-loops count twice.
+    degree: Vec<f64>,
+    /// The total weight of all edges.
+    total: f64,
+}
+
+impl Level {
+    fn new<G, F>(indexed: &Indexed<'_, G>, mut weight: F) -> Self
+    where
+        G: Graph + ?Sized,
+        F: FnMut(&G::EdgeWeight) -> f64,
+    {
+        let edges = indexed
+            .edges
+            .iter()
+            .map(|edge| (edge.source, edge.target, weight(edge.weight)));
+
+        Level::from_edges(indexed.vertex_count(), edges)
+    }
+
+    fn from_edges(count: usize, edges: impl Iterator<Item = (usize, usize, f64)>) -> Self {
+        let mut between = vec![HashMap::<usize, f64>::new(); count];
+        let mut loops = vec![0.0; count];
+        let mut total = 0.0;
+
+        for (a, b, weight) in edges {
+            total += weight;
+
+            if a == b {
+                loops[a] += weight;
+            } else {
+                *between[a].entry(b).or_default() += weight;
+                *between[b].entry(a).or_default() += weight;
+            }
+        }
+
+        let neighbours = between
+            .into_iter()
+            .map(|between| {
+                let mut neighbours = between.into_iter().collect::<Vec<_>>();
+                neighbours.sort_unstable_by_key(|&(neighbour, _)| neighbour);
+                neighbours
+            })
+            .collect::<Vec<Vec<_>>>();
+
+        let degree = neighbours
+            .iter()
+            .zip(&loops)
+            .map(|(neighbours, &loops)| {
+                neighbours.iter().map(|&(_, weight)| weight).sum::<f64>() + 2.0 * loops
+            })
+            .collect();
+
+        Level {
+            neighbours,
+            loops,
+            degree,
+            total,
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    /// Moves vertices to neighbouring communities while doing so raises modularity, starting from
+    /// one community per vertex, and returns the community of each vertex.
+    fn move_vertices(&self, rng: &mut Rng) -> Vec<usize> {
+        let mut community = (0..self.len()).collect::<Vec<_>>();
+        let mut community_degree = self.degree.clone();
+
+        if self.total <= 0.0 {
+            return community;
+        }
+
+        let mut order = community.clone();
+        rng.shuffle(&mut order);
+
+        let mut moved = true;
+        while moved {
+            moved = false;
+
+            for &vertex in &order {
+                let current = community[vertex];
+                let degree = self.degree[vertex];
+                community_degree[current] -= degree;
+
+                let mut links = HashMap::<usize, f64>::new();
+                links.insert(current, 0.0);
+                for &(neighbour, weight) in &self.neighbours[vertex] {
+                    *links.entry(community[neighbour]).or_default() += weight;
+                }
+
+                // The gain of joining a community, up to a factor common to every community.
+                let gain = |target: usize, links: f64| {
+                    links - community_degree[target] * degree / (2.0 * self.total)
+                };
+
+                let mut best = (current, gain(current, links[&current]));
+                let mut candidates = links.into_iter().collect::<Vec<_>>();
+                candidates.sort_unstable_by_key(|&(target, _)| target);
+
+                for (target, links) in candidates {
+                    let gain = gain(target, links);
+                    if gain > best.1 + MIN_GAIN {
+                        best = (target, gain);
+                    }
+                }
+
+                community_degree[best.0] += degree;
+
+                if best.0 != current {
+                    community[vertex] = best.0;
+                    moved = true;
+                }
+            }
+        }
+
+        community
+    }
+
+    /// Collapses every community into a single vertex whose self-loops carry the weight of the
+    /// edges inside it.
+    fn aggregate(&self, community: &[usize], count: usize) -> Self {
+        let internal = (0..self.len())
+            .map(|vertex| (community[vertex], community[vertex], self.loops[vertex]));
+
+        let external = (0..self.len()).flat_map(|vertex| {
+            self.neighbours[vertex]
+                .iter()
+                .filter(move |&&(neighbour, _)| vertex < neighbour)
+                .map(move |&(neighbour, weight)| (community[vertex], community[neighbour], weight))
+        });
+
+        Level::from_edges(count, internal.chain(external))
+    }
+
+    fn modularity(&self, community: &[usize]) -> f64 {
+        if self.total <= 0.0 {
+            return 0.0;
+        }
+
+        let count = community.iter().max().map_or(0, |&max| max + 1);
+        let mut inside = vec![0.0; count];
+        let mut degree = vec![0.0; count];
+
+        for vertex in 0..self.len() {
+            inside[community[vertex]] += self.loops[vertex];
+            degree[community[vertex]] += self.degree[vertex];
+
+            for &(neighbour, weight) in &self.neighbours[vertex] {
+                if vertex < neighbour && community[vertex] == community[neighbour] {
+                    inside[community[vertex]] += weight;
+                }
+            }
+        }
+
+        inside
+            .into_iter()
+            .zip(degree)
+            .map(|(inside, degree)| inside / self.total - (degree / (2.0 * self.total)).powi(2))
+            .sum()
+    }
+}
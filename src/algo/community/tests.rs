@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{unstable_graph::*, Cyclic, Undirected};
+
+/// Two 5-cliques joined by a single light edge.
+fn barbell() -> UnstableGraph<(), f64, Undirected, Cyclic> {
+    let mut graph = UnstableGraph::<_, _, Undirected, Cyclic>::undirected();
+    let v = (0..10)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    for side in [0, 5] {
+        for a in side..side + 5 {
+            for b in a + 1..side + 5 {
+                graph.insert_edge(v[a], v[b], 1.0).unwrap();
+            }
+        }
+    }
+    graph.insert_edge(v[4], v[5], 0.5).unwrap();
+
+    graph
+}
+
+fn assert_splits_barbell(communities: &Communities<VertexIndex>) {
+    for a in 0..10 {
+        for b in 0..10 {
+            let membership = |i| communities.membership[&VertexIndex::new(i)];
+            assert_eq!(membership(a) == membership(b), (a < 5) == (b < 5));
+        }
+    }
+}
+
+#[test]
+fn louvain_separates_cliques() {
+    let graph = barbell();
+    let communities = louvain(&graph, |&w| w, 7);
+
+    assert_splits_barbell(&communities);
+    assert!(communities.modularity > 0.4);
+
+    let again = louvain(&graph, |&w| w, 7);
+    assert_eq!(communities.membership, again.membership);
+    assert!(
+        (modularity(&graph, &communities.membership, |&w| w) - communities.modularity).abs() < 1e-9
+    );
+}
+
+#[test]
+fn label_propagation_separates_cliques() {
+    let graph = barbell();
+
+    for seed in 0..10 {
+        let communities = label_propagation(&graph, |&w| w, seed);
+        assert_splits_barbell(&communities);
+        assert_eq!(
+            communities.membership,
+            label_propagation(&graph, |&w| w, seed).membership
+        );
+    }
+}
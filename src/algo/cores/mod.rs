@@ -0,0 +1,69 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+use std::collections::HashMap;
+
+/// Returns the core number of each vertex of `graph`: the largest `k` such that the vertex
+/// belongs to a subgraph in which every vertex has at least `k` neighbours. Computed with the
+/// `O(m)` algorithm of [Batagelj and Zaversnik](https://arxiv.org/abs/cs/0310049). Edge direction,
+/// parallel edges and self-loops are ignored.
+pub fn core_numbers<G: Graph + ?Sized>(graph: &G) -> HashMap<G::VertexId, usize> {
+    let indexed = Indexed::new(graph);
+    let neighbours = indexed.neighbour_sets();
+    let count = neighbours.len();
+
+    let mut degree = neighbours.iter().map(Vec::len).collect::<Vec<_>>();
+    let max_degree = degree.iter().copied().max().unwrap_or(0);
+
+    // Vertices sorted by degree, with the start of each degree's bucket and each vertex's
+    // position, so that a vertex can be moved down a bucket in constant time.
+    let mut bucket_start = vec![0; max_degree + 1];
+    for &d in &degree {
+        bucket_start[d] += 1;
+    }
+    let mut start = 0;
+    for size in &mut bucket_start {
+        (*size, start) = (start, start + *size);
+    }
+
+    let mut position = vec![0; count];
+    let mut order = vec![0; count];
+    let mut next_slot = bucket_start.clone();
+    for vertex in 0..count {
+        position[vertex] = next_slot[degree[vertex]];
+        order[position[vertex]] = vertex;
+        next_slot[degree[vertex]] += 1;
+    }
+
+    for i in 0..count {
+        let vertex = order[i];
+
+        for &neighbour in &neighbours[vertex] {
+            if degree[neighbour] > degree[vertex] {
+                let d = degree[neighbour];
+                let first = order[bucket_start[d]];
+
+                if first != neighbour {
+                    order.swap(position[neighbour], bucket_start[d]);
+                    position.swap(neighbour, first);
+                }
+
+                bucket_start[d] += 1;
+                degree[neighbour] -= 1;
+            }
+        }
+    }
+
+    indexed.vertices.iter().copied().zip(degree).collect()
+}
+
+/// Returns the vertices of the `k`-core of `graph`, the largest subgraph in which every vertex
+/// has at least `k` neighbours. Edge direction, parallel edges and self-loops are ignored.
+pub fn k_core<G: Graph + ?Sized>(graph: &G, k: usize) -> Vec<G::VertexId> {
+    core_numbers(graph)
+        .into_iter()
+        .filter(|&(_, core)| core >= k)
+        .map(|(vertex, _)| vertex)
+        .collect()
+}
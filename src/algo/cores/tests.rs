@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{stable_graph::StableGraph, unstable_graph::*, Cyclic, Undirected};
+
+#[test]
+fn core_numbers_of_nested_shells() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = (0..8)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    // A 4-clique, a vertex hanging off it by two edges, a path tail and an isolated vertex.
+    for (a, b) in [
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (4, 0),
+        (4, 1),
+        (4, 5),
+        (5, 6),
+    ] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    let cores = core_numbers(&graph);
+    assert_eq!(
+        v.iter().map(|i| cores[i]).collect::<Vec<_>>(),
+        vec![3, 3, 3, 3, 2, 1, 1, 0]
+    );
+
+    let mut two_core = k_core(&graph, 2);
+    two_core.sort();
+    assert_eq!(two_core, v[..5]);
+}
+
+#[test]
+fn core_numbers_of_stable_cycle() {
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let v = (0..4).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    for i in 0..4 {
+        graph.insert_edge(v[i], v[(i + 1) % 4], ()).unwrap();
+    }
+
+    assert!(core_numbers(&graph).values().all(|&core| core == 2));
+}
@@ -0,0 +1,145 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+
+/// The reason that a graph has no Eulerian path or circuit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotEulerian<N> {
+    /// The degrees of these vertices rule one out. For a circuit, these are the vertices with an
+    /// odd degree, or an in-degree different from their out-degree. For a path, they are the
+    /// same vertices when there are too many of them or they are out of balance by more than one.
+    DegreeImbalance(Vec<N>),
+    /// The edges do not all lie in one connected component, ignoring direction.
+    Disconnected,
+}
+
+/// Finds an [Eulerian circuit](https://en.wikipedia.org/wiki/Eulerian_path) of `graph`, a closed
+/// walk that uses every edge exactly once, using Hierholzer's algorithm. Parallel edges and
+/// self-loops are each walked separately. The circuit is returned as its sequence of edges, and is
+/// empty if `graph` has no edges.
+pub fn eulerian_circuit<G: Graph + ?Sized>(
+    graph: &G,
+) -> Result<Vec<G::EdgeId>, NotEulerian<G::VertexId>> {
+    let indexed = Indexed::new(graph);
+    let balance = balance(&indexed);
+
+    let unbalanced = unbalanced(&indexed, &balance);
+    if !unbalanced.is_empty() {
+        return Err(NotEulerian::DegreeImbalance(unbalanced));
+    }
+
+    let start = (0..indexed.vertex_count()).find(|&v| !indexed.adjacent[v].is_empty());
+    walk(&indexed, start)
+}
+
+/// Finds an [Eulerian path](https://en.wikipedia.org/wiki/Eulerian_path) of `graph`, a walk that
+/// uses every edge exactly once, using Hierholzer's algorithm. Parallel edges and self-loops are
+/// each walked separately. The path is returned as its sequence of edges, is a circuit if `graph`
+/// has one, and is empty if `graph` has no edges.
+pub fn eulerian_path<G: Graph + ?Sized>(
+    graph: &G,
+) -> Result<Vec<G::EdgeId>, NotEulerian<G::VertexId>> {
+    let indexed = Indexed::new(graph);
+    let balance = balance(&indexed);
+    let unbalanced = unbalanced(&indexed, &balance);
+
+    // A directed path starts where there is one more edge out than in; an undirected one starts
+    // at either vertex of odd degree.
+    let start = match indexed.directed {
+        true => (0..balance.len()).find(|&v| balance[v] == 1),
+        false => (0..balance.len()).find(|&v| balance[v] % 2 != 0),
+    };
+
+    let valid = match indexed.directed {
+        true => balance.iter().all(|b| b.abs() <= 1) && unbalanced.len() <= 2,
+        false => unbalanced.len() <= 2,
+    };
+
+    if !valid {
+        return Err(NotEulerian::DegreeImbalance(unbalanced));
+    }
+
+    let start =
+        start.or_else(|| (0..indexed.vertex_count()).find(|&v| !indexed.adjacent[v].is_empty()));
+    walk(&indexed, start)
+}
+
+/// Returns the out-degree minus the in-degree of every vertex of a directed graph, or the degree
+/// of every vertex of an undirected one.
+fn balance<G: Graph + ?Sized>(indexed: &Indexed<'_, G>) -> Vec<isize> {
+    let mut balance = vec![0; indexed.vertex_count()];
+
+    for edge in &indexed.edges {
+        balance[edge.source] += 1;
+
+        match indexed.directed {
+            true => balance[edge.target] -= 1,
+            false => balance[edge.target] += 1,
+        }
+    }
+
+    balance
+}
+
+fn unbalanced<G: Graph + ?Sized>(indexed: &Indexed<'_, G>, balance: &[isize]) -> Vec<G::VertexId> {
+    (0..balance.len())
+        .filter(|&v| match indexed.directed {
+            true => balance[v] != 0,
+            false => balance[v] % 2 != 0,
+        })
+        .map(|v| indexed.vertices[v])
+        .collect()
+}
+
+/// Walks every edge reachable from `start` with Hierholzer's algorithm, splicing in detours from
+/// vertices on the walk that still have unused edges.
+fn walk<G: Graph + ?Sized>(
+    indexed: &Indexed<'_, G>,
+    start: Option<usize>,
+) -> Result<Vec<G::EdgeId>, NotEulerian<G::VertexId>> {
+    let Some(start) = start else {
+        return Ok(vec![]);
+    };
+
+    let adjacency = match indexed.directed {
+        true => &indexed.outgoing,
+        false => &indexed.adjacent,
+    };
+
+    let mut used = vec![false; indexed.edges.len()];
+    let mut cursor = vec![0; indexed.vertex_count()];
+    let mut stack = vec![(start, None)];
+    let mut trail = Vec::with_capacity(indexed.edges.len());
+
+    while let Some(&(vertex, via)) = stack.last() {
+        let next = adjacency[vertex][cursor[vertex]..]
+            .iter()
+            .position(|&(_, edge)| !used[edge]);
+
+        match next {
+            Some(offset) => {
+                cursor[vertex] += offset + 1;
+
+                let (neighbour, edge) = adjacency[vertex][cursor[vertex] - 1];
+                used[edge] = true;
+                stack.push((neighbour, Some(edge)));
+            }
+            None => {
+                cursor[vertex] = adjacency[vertex].len();
+                stack.pop();
+                trail.extend(via);
+            }
+        }
+    }
+
+    if trail.len() != indexed.edges.len() {
+        return Err(NotEulerian::Disconnected);
+    }
+
+    trail.reverse();
+    Ok(trail
+        .into_iter()
+        .map(|edge| indexed.edges[edge].id)
+        .collect())
+}
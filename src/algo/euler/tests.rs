@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{unstable_graph::*, Cyclic, Cyclicness, Directed, EdgeType, Undirected};
+
+/// Checks that `trail` uses every edge once and that consecutive edges share a vertex, following
+/// edge direction if `directed`, and returns the vertices it starts and ends at.
+fn ends<D: EdgeType, C: Cyclicness>(
+    graph: &UnstableGraph<(), (), D, C>,
+    trail: &[EdgeIndex],
+    directed: bool,
+) -> (VertexIndex, VertexIndex) {
+    let mut sorted = trail.to_vec();
+    sorted.sort();
+    assert_eq!(
+        sorted,
+        (0..graph.edge_count())
+            .map(EdgeIndex::new)
+            .collect::<Vec<_>>()
+    );
+
+    let (source, target) = graph.edges[trail[0].index()].vertex_indices();
+    let starts = if directed {
+        vec![source]
+    } else {
+        vec![source, target]
+    };
+
+    starts
+        .into_iter()
+        .find_map(|start| {
+            trail
+                .iter()
+                .try_fold(start, |at, &edge| {
+                    match graph.edges[edge.index()].vertex_indices() {
+                        (a, b) if a == at => Some(b),
+                        (a, b) if !directed && b == at => Some(a),
+                        _ => None,
+                    }
+                })
+                .map(|end| (start, end))
+        })
+        .expect("consecutive edges share a vertex")
+}
+
+#[test]
+fn directed_multigraph_circuit_and_path() {
+    let mut graph = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let v = (0..3)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    // Parallel edges and a self-loop, like the transitions of a small state machine.
+    for (a, b) in [(0, 1), (1, 0), (0, 1), (1, 2), (2, 2)] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    assert_eq!(
+        eulerian_circuit(&graph),
+        Err(NotEulerian::DegreeImbalance(vec![v[0], v[2]]))
+    );
+
+    let path = eulerian_path(&graph).unwrap();
+    assert_eq!(ends(&graph, &path, true), (v[0], v[2]));
+
+    graph.insert_edge(v[2], v[0], ()).unwrap();
+    graph.insert_edge(v[1], v[0], ()).unwrap();
+    graph.insert_edge(v[0], v[1], ()).unwrap();
+
+    let circuit = eulerian_circuit(&graph).unwrap();
+    let (start, end) = ends(&graph, &circuit, true);
+    assert_eq!(start, end);
+}
+
+#[test]
+fn undirected_reasons() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = (0..6)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    assert_eq!(eulerian_circuit(&graph), Err(NotEulerian::Disconnected));
+
+    graph.insert_edge(v[2], v[3], ()).unwrap();
+    let path = eulerian_path(&graph).unwrap();
+    let (start, end) = ends(&graph, &path, false);
+    assert!((start, end) == (v[2], v[3]) || (start, end) == (v[3], v[2]));
+
+    graph.insert_edge(v[0], v[4], ()).unwrap();
+    assert_eq!(
+        eulerian_path(&graph),
+        Err(NotEulerian::DegreeImbalance(vec![v[0], v[2], v[3], v[4]]))
+    );
+}
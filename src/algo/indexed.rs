@@ -0,0 +1,131 @@
+use crate::graph::traits::Graph;
+use std::collections::HashMap;
+
+/// An edge of an [`Indexed`] graph.
+pub struct IndexedEdge<'g, G: Graph + ?Sized> {
+    pub id: G::EdgeId,
+    pub source: usize,
+    pub target: usize,
+    pub weight: &'g G::EdgeWeight,
+}
+
+/// A snapshot of a graph in which vertices are renumbered densely from `0` and every vertex
+/// knows its incident edges, which is the shape most algorithms want to work on.
+#[doc(hidden)]
+pub struct Indexed<'g, G: Graph + ?Sized> {
+    pub directed: bool,
+    pub vertices: Vec<G::VertexId>,
+    pub vertex_weights: Vec<&'g G::VertexWeight>,
+    pub positions: HashMap<G::VertexId, usize>,
+    pub edges: Vec<IndexedEdge<'g, G>>,
+    /// The `(neighbour, edge)` pairs leaving each vertex. Undirected edges leave both endpoints.
+    pub outgoing: Vec<Vec<(usize, usize)>>,
+    /// The `(neighbour, edge)` pairs entering each vertex. Undirected edges enter both endpoints.
+    pub incoming: Vec<Vec<(usize, usize)>>,
+    /// The `(neighbour, edge)` pairs incident to each vertex regardless of direction. A self-loop
+    /// appears once.
+    pub adjacent: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'g, G: Graph + ?Sized> Indexed<'g, G> {
+    /// Takes a snapshot of `graph`.
+    pub fn new(graph: &'g G) -> Self {
+        let (vertex_count, edge_count) = (graph.vertex_count(), graph.edge_count());
+
+        let mut indexed = Indexed {
+            directed: graph.is_directed(),
+            vertices: Vec::with_capacity(vertex_count),
+            vertex_weights: Vec::with_capacity(vertex_count),
+            positions: HashMap::with_capacity(vertex_count),
+            edges: Vec::with_capacity(edge_count),
+            outgoing: Vec::with_capacity(vertex_count),
+            incoming: Vec::with_capacity(vertex_count),
+            adjacent: Vec::with_capacity(vertex_count),
+        };
+
+        for id in graph.vertex_ids() {
+            let weight = graph
+                .vertex_weight(id)
+                .expect("Vertex without a weight in graph.");
+            indexed.push_vertex(id, weight);
+        }
+
+        for id in graph.edge_ids() {
+            let (source, target) = graph
+                .edge_endpoints(id)
+                .expect("Edge without endpoints in graph.");
+            let weight = graph
+                .edge_weight(id)
+                .expect("Edge without a weight in graph.");
+            indexed.push_edge(id, source, target, weight);
+        }
+
+        indexed
+    }
+
+    fn push_vertex(&mut self, id: G::VertexId, weight: &'g G::VertexWeight) {
+        self.positions.insert(id, self.vertices.len());
+        self.vertices.push(id);
+        self.vertex_weights.push(weight);
+        self.outgoing.push(vec![]);
+        self.incoming.push(vec![]);
+        self.adjacent.push(vec![]);
+    }
+
+    fn push_edge(
+        &mut self,
+        id: G::EdgeId,
+        source: G::VertexId,
+        target: G::VertexId,
+        weight: &'g G::EdgeWeight,
+    ) {
+        let index = self.edges.len();
+        let (source, target) = (self.positions[&source], self.positions[&target]);
+
+        self.outgoing[source].push((target, index));
+        self.incoming[target].push((source, index));
+        self.adjacent[source].push((target, index));
+
+        if source != target {
+            self.adjacent[target].push((source, index));
+
+            if !self.directed {
+                self.outgoing[target].push((source, index));
+                self.incoming[source].push((target, index));
+            }
+        }
+
+        self.edges.push(IndexedEdge {
+            id,
+            source,
+            target,
+            weight,
+        });
+    }
+
+    /// Returns the number of vertices in this snapshot.
+    #[inline]
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the distinct neighbours of every vertex regardless of edge direction, ignoring
+    /// self-loops.
+    pub fn neighbour_sets(&self) -> Vec<Vec<usize>> {
+        self.adjacent
+            .iter()
+            .enumerate()
+            .map(|(vertex, adjacent)| {
+                let mut neighbours = adjacent
+                    .iter()
+                    .map(|&(neighbour, _)| neighbour)
+                    .filter(|&neighbour| neighbour != vertex)
+                    .collect::<Vec<_>>();
+
+                neighbours.sort_unstable();
+                neighbours.dedup();
+                neighbours
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,485 @@
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+use std::collections::HashMap;
+
+const NONE: usize = usize::MAX;
+
+/// The kind of mapping that a [`Vf2`] search looks for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// A mapping between all vertices of two graphs that preserves edges in both directions.
+    Isomorphism,
+    /// A mapping onto some vertices of the target under which pattern vertices are joined by as
+    /// many edges as their images are.
+    Induced,
+    /// A mapping onto some vertices of the target under which pattern vertices are joined by at
+    /// most as many edges as their images are, so the target may have extra edges.
+    Monomorphism,
+}
+
+/// Returns `true` if `g0` and `g1` are isomorphic, that is if there is a one-to-one mapping of
+/// their vertices under which every pair of vertices is joined by the same number of edges.
+/// Graphs of different directedness are never isomorphic.
+pub fn is_isomorphic<G0, G1>(g0: &G0, g1: &G1) -> bool
+where
+    G0: Graph + ?Sized,
+    G1: Graph + ?Sized,
+{
+    is_isomorphic_matching(g0, g1, |_, _| true, |_, _| true)
+}
+
+/// Returns `true` if `g0` and `g1` are isomorphic under a mapping that only pairs vertices whose
+/// weights satisfy `vertex_match` and edges whose weights satisfy `edge_match`.
+pub fn is_isomorphic_matching<G0, G1, VM, EM>(
+    g0: &G0,
+    g1: &G1,
+    vertex_match: VM,
+    edge_match: EM,
+) -> bool
+where
+    G0: Graph + ?Sized,
+    G1: Graph + ?Sized,
+    VM: FnMut(&G0::VertexWeight, &G1::VertexWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    let (g0, g1) = (Indexed::new(g0), Indexed::new(g1));
+
+    if g0.vertex_count() != g1.vertex_count() || g0.edges.len() != g1.edges.len() {
+        return false;
+    }
+
+    Vf2::new(g0, g1, vertex_match, edge_match, Mode::Isomorphism)
+        .next()
+        .is_some()
+}
+
+/// Returns an iterator over the induced subgraph isomorphisms from `pattern` to `target`, that is
+/// the ways that `pattern` occurs as an induced subgraph of `target`.
+///
+/// Each mapping sends every vertex of `pattern` to a distinct vertex of `target` such that any two
+/// pattern vertices are joined by as many edges as their images are, with vertex and edge weights
+/// satisfying `vertex_match` and `edge_match`. Target vertices that are joined by an edge which the
+/// pattern lacks rule a mapping out; see [`subgraph_monomorphisms_iter`] for a search that allows
+/// them. The search follows the [VF2](https://doi.org/10.1109/TPAMI.2004.75) algorithm.
+pub fn subgraph_isomorphisms_iter<'a, G0, G1, VM, EM>(
+    pattern: &'a G0,
+    target: &'a G1,
+    vertex_match: VM,
+    edge_match: EM,
+) -> SubgraphIsomorphisms<'a, G0, G1, VM, EM>
+where
+    G0: Graph + ?Sized,
+    G1: Graph + ?Sized,
+    VM: FnMut(&G0::VertexWeight, &G1::VertexWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    SubgraphIsomorphisms(Vf2::new(
+        Indexed::new(pattern),
+        Indexed::new(target),
+        vertex_match,
+        edge_match,
+        Mode::Induced,
+    ))
+}
+
+/// Returns an iterator over the subgraph monomorphisms from `pattern` to `target`, that is the
+/// ways that `pattern` occurs as a subgraph of `target` that need not be induced.
+///
+/// Each mapping sends every vertex of `pattern` to a distinct vertex of `target` such that every
+/// edge of `pattern` is matched by a distinct edge between the images of its endpoints, with vertex
+/// and edge weights satisfying `vertex_match` and `edge_match`. Unlike
+/// [`subgraph_isomorphisms_iter`], the images may be joined by edges that the pattern lacks.
+pub fn subgraph_monomorphisms_iter<'a, G0, G1, VM, EM>(
+    pattern: &'a G0,
+    target: &'a G1,
+    vertex_match: VM,
+    edge_match: EM,
+) -> SubgraphIsomorphisms<'a, G0, G1, VM, EM>
+where
+    G0: Graph + ?Sized,
+    G1: Graph + ?Sized,
+    VM: FnMut(&G0::VertexWeight, &G1::VertexWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    SubgraphIsomorphisms(Vf2::new(
+        Indexed::new(pattern),
+        Indexed::new(target),
+        vertex_match,
+        edge_match,
+        Mode::Monomorphism,
+    ))
+}
+
+/// An iterator over mappings from a pattern into a target graph, created by
+/// [`subgraph_isomorphisms_iter`] or [`subgraph_monomorphisms_iter`].
+pub struct SubgraphIsomorphisms<'a, G0, G1, VM, EM>(Vf2<'a, G0, G1, VM, EM>)
+where
+    G0: Graph + ?Sized,
+    G1: Graph + ?Sized;
+
+impl<G0, G1, VM, EM> Iterator for SubgraphIsomorphisms<'_, G0, G1, VM, EM>
+where
+    G0: Graph + ?Sized,
+    G1: Graph + ?Sized,
+    VM: FnMut(&G0::VertexWeight, &G1::VertexWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    type Item = HashMap<G0::VertexId, G1::VertexId>;
+
+    /// Gets the next item of the iterator.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// One side of a [`Vf2`] search.
+struct Side<'a, G: Graph + ?Sized> {
+    graph: Indexed<'a, G>,
+    /// The edges joining each ordered pair of vertices; undirected edges are filed under both
+    /// orders.
+    between: HashMap<(usize, usize), Vec<usize>>,
+    /// The vertex that each vertex is mapped to on the other side.
+    core: Vec<usize>,
+    /// The depth of the search at which each vertex became adjacent to a mapped vertex, or `0`.
+    terminal: Vec<usize>,
+}
+
+impl<'a, G: Graph + ?Sized> Side<'a, G> {
+    fn new(graph: Indexed<'a, G>) -> Self {
+        let mut between = HashMap::<_, Vec<_>>::new();
+
+        for (index, edge) in graph.edges.iter().enumerate() {
+            between
+                .entry((edge.source, edge.target))
+                .or_default()
+                .push(index);
+
+            if !graph.directed && edge.source != edge.target {
+                between
+                    .entry((edge.target, edge.source))
+                    .or_default()
+                    .push(index);
+            }
+        }
+
+        Side {
+            core: vec![NONE; graph.vertex_count()],
+            terminal: vec![0; graph.vertex_count()],
+            graph,
+            between,
+        }
+    }
+
+    #[inline]
+    fn between(&self, a: usize, b: usize) -> &[usize] {
+        self.between.get(&(a, b)).map_or(&[], Vec::as_slice)
+    }
+
+    fn map(&mut self, vertex: usize, to: usize, depth: usize) {
+        self.core[vertex] = to;
+
+        if self.terminal[vertex] == 0 {
+            self.terminal[vertex] = depth;
+        }
+
+        for &(neighbour, _) in &self.graph.adjacent[vertex] {
+            if self.terminal[neighbour] == 0 {
+                self.terminal[neighbour] = depth;
+            }
+        }
+    }
+
+    fn unmap(&mut self, vertex: usize, depth: usize) {
+        self.core[vertex] = NONE;
+
+        if self.terminal[vertex] == depth {
+            self.terminal[vertex] = 0;
+        }
+
+        for &(neighbour, _) in &self.graph.adjacent[vertex] {
+            if self.terminal[neighbour] == depth {
+                self.terminal[neighbour] = 0;
+            }
+        }
+    }
+
+    /// Counts the edges from `vertex` in the given adjacency lists that lead to unmapped vertices
+    /// inside and outside of the terminal set.
+    fn lookahead(&self, adjacency: &[(usize, usize)]) -> (usize, usize) {
+        adjacency
+            .iter()
+            .filter(|&&(neighbour, _)| self.core[neighbour] == NONE)
+            .fold((0, 0), |(terminal, new), &(neighbour, _)| {
+                match self.terminal[neighbour] {
+                    0 => (terminal, new + 1),
+                    _ => (terminal + 1, new),
+                }
+            })
+    }
+}
+
+/// A frame of the [`Vf2`] search: the pattern vertex being mapped, the target vertices it may be
+/// mapped to and the one it currently is.
+struct Frame {
+    vertex: usize,
+    candidates: Vec<usize>,
+    position: usize,
+    mapped: usize,
+}
+
+struct Vf2<'a, G0: Graph + ?Sized, G1: Graph + ?Sized, VM, EM> {
+    g0: Side<'a, G0>,
+    g1: Side<'a, G1>,
+    vertex_match: VM,
+    edge_match: EM,
+    mode: Mode,
+    stack: Vec<Frame>,
+    /// Whether the search is over, or has not yet started if `stack` is empty.
+    finished: bool,
+}
+
+impl<'a, G0, G1, VM, EM> Vf2<'a, G0, G1, VM, EM>
+where
+    G0: Graph + ?Sized,
+    G1: Graph + ?Sized,
+    VM: FnMut(&G0::VertexWeight, &G1::VertexWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    fn new(
+        g0: Indexed<'a, G0>,
+        g1: Indexed<'a, G1>,
+        vertex_match: VM,
+        edge_match: EM,
+        mode: Mode,
+    ) -> Self {
+        let finished = g0.directed != g1.directed || g0.vertex_count() > g1.vertex_count();
+
+        Vf2 {
+            g0: Side::new(g0),
+            g1: Side::new(g1),
+            vertex_match,
+            edge_match,
+            mode,
+            stack: vec![],
+            finished,
+        }
+    }
+
+    fn next(&mut self) -> Option<HashMap<G0::VertexId, G1::VertexId>> {
+        if self.finished {
+            return None;
+        }
+
+        if self.stack.is_empty() && !self.push_frame() {
+            self.finished = true;
+            return Some(HashMap::new());
+        }
+
+        while !self.stack.is_empty() {
+            let depth = self.stack.len();
+            let frame = &mut self.stack[depth - 1];
+            let vertex = frame.vertex;
+            let mapped = std::mem::replace(&mut frame.mapped, NONE);
+
+            if mapped != NONE {
+                self.g0.unmap(vertex, depth);
+                self.g1.unmap(mapped, depth);
+            }
+
+            let Some(candidate) = self.next_feasible() else {
+                self.stack.pop();
+                continue;
+            };
+
+            self.stack.last_mut().unwrap().mapped = candidate;
+            self.g0.map(vertex, candidate, depth);
+            self.g1.map(candidate, vertex, depth);
+
+            if !self.push_frame() {
+                return Some(self.mapping());
+            }
+        }
+
+        self.finished = true;
+        None
+    }
+
+    /// Advances the top frame to its next feasible candidate.
+    fn next_feasible(&mut self) -> Option<usize> {
+        loop {
+            let frame = self.stack.last_mut().unwrap();
+            let candidate = *frame.candidates.get(frame.position)?;
+            let vertex = frame.vertex;
+            frame.position += 1;
+
+            if self.feasible(vertex, candidate) {
+                return Some(candidate);
+            }
+        }
+    }
+
+    /// Pushes a frame for the next pattern vertex to map, returning `false` if every pattern
+    /// vertex is already mapped.
+    fn push_frame(&mut self) -> bool {
+        let in_terminal = unmapped(&self.g0, true).next().is_some();
+
+        let Some(vertex) = unmapped(&self.g0, in_terminal).next() else {
+            return false;
+        };
+
+        // An unmapped pattern vertex outside of the terminal set may still be mapped into the
+        // target's terminal set when the target may have extra edges.
+        let candidates = match (self.mode, in_terminal) {
+            (Mode::Monomorphism, false) => (0..self.g1.core.len())
+                .filter(|&v| self.g1.core[v] == NONE)
+                .collect(),
+            _ => unmapped(&self.g1, in_terminal).collect(),
+        };
+
+        self.stack.push(Frame {
+            vertex,
+            candidates,
+            position: 0,
+            mapped: NONE,
+        });
+
+        true
+    }
+
+    fn mapping(&self) -> HashMap<G0::VertexId, G1::VertexId> {
+        self.g0
+            .core
+            .iter()
+            .enumerate()
+            .map(|(v0, &v1)| (self.g0.graph.vertices[v0], self.g1.graph.vertices[v1]))
+            .collect()
+    }
+
+    /// Returns `true` if `v0` in the pattern may be mapped to `v1` in the target given the
+    /// vertices already mapped.
+    fn feasible(&mut self, v0: usize, v1: usize) -> bool {
+        let exact = self.mode == Mode::Isomorphism;
+        let fits = |a: usize, b: usize| if exact { a == b } else { a <= b };
+
+        let (g0, g1) = (&self.g0.graph, &self.g1.graph);
+
+        if !fits(g0.outgoing[v0].len(), g1.outgoing[v1].len())
+            || !fits(g0.incoming[v0].len(), g1.incoming[v1].len())
+        {
+            return false;
+        }
+
+        if !(self.vertex_match)(g0.vertex_weights[v0], g1.vertex_weights[v1]) {
+            return false;
+        }
+
+        // Edges to mapped vertices must correspond exactly, which also covers self-loops since
+        // `v0` is about to be mapped to `v1`.
+        for index in 0..self.g0.graph.adjacent[v0].len() {
+            let n0 = self.g0.graph.adjacent[v0][index].0;
+            let n1 = if n0 == v0 { v1 } else { self.g0.core[n0] };
+
+            if n1 != NONE
+                && !(self.edges_correspond((v0, n0), (v1, n1))
+                    && self.edges_correspond((n0, v0), (n1, v1)))
+            {
+                return false;
+            }
+        }
+
+        // Only a monomorphism may leave edges between mapped target vertices unmatched.
+        for &(n1, _) in &self.g1.graph.adjacent[v1] {
+            let n0 = if n1 == v1 { v0 } else { self.g1.core[n1] };
+
+            if n0 != NONE
+                && self.mode != Mode::Monomorphism
+                && (self.g0.between(v0, n0).len() != self.g1.between(v1, n1).len()
+                    || self.g0.between(n0, v0).len() != self.g1.between(n1, v1).len())
+            {
+                return false;
+            }
+        }
+
+        let (term_out0, new_out0) = self.g0.lookahead(&self.g0.graph.outgoing[v0]);
+        let (term_out1, new_out1) = self.g1.lookahead(&self.g1.graph.outgoing[v1]);
+        let (term_in0, new_in0) = self.g0.lookahead(&self.g0.graph.incoming[v0]);
+        let (term_in1, new_in1) = self.g1.lookahead(&self.g1.graph.incoming[v1]);
+
+        // The neighbours of a pattern vertex in the terminal set must be mapped into the target's
+        // terminal set, but under a monomorphism the others may be mapped into either.
+        match self.mode {
+            Mode::Monomorphism => {
+                term_out0 <= term_out1
+                    && term_out0 + new_out0 <= term_out1 + new_out1
+                    && term_in0 <= term_in1
+                    && term_in0 + new_in0 <= term_in1 + new_in1
+            }
+            _ => {
+                fits(term_out0, term_out1)
+                    && fits(new_out0, new_out1)
+                    && fits(term_in0, term_in1)
+                    && fits(new_in0, new_in1)
+            }
+        }
+    }
+
+    /// Returns `true` if the edges from `a.0` to `a.1` in the pattern can be paired up one-to-one
+    /// with the edges from `b.0` to `b.1` in the target such that each pair satisfies
+    /// `edge_match`. Under a monomorphism, target edges may be left over.
+    fn edges_correspond(&mut self, a: (usize, usize), b: (usize, usize)) -> bool {
+        let e0 = self.g0.between(a.0, a.1);
+        let e1 = self.g1.between(b.0, b.1);
+
+        if e0.len() > e1.len() || (self.mode != Mode::Monomorphism && e0.len() != e1.len()) {
+            return false;
+        }
+
+        let compatible = e0
+            .iter()
+            .map(|&x| {
+                e1.iter()
+                    .map(|&y| {
+                        (self.edge_match)(
+                            self.g0.graph.edges[x].weight,
+                            self.g1.graph.edges[y].weight,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut owner = vec![NONE; e1.len()];
+
+        (0..e0.len()).all(|x| {
+            let mut seen = vec![false; e1.len()];
+            pair_up(x, &compatible, &mut seen, &mut owner)
+        })
+    }
+}
+
+/// Returns the unmapped vertices of `side` that are inside or outside of its terminal set.
+fn unmapped<'s, G: Graph + ?Sized>(
+    side: &'s Side<'_, G>,
+    in_terminal: bool,
+) -> impl Iterator<Item = usize> + 's {
+    (0..side.core.len())
+        .filter(move |&v| side.core[v] == NONE && (side.terminal[v] != 0) == in_terminal)
+}
+
+/// Finds an augmenting path for the pattern edge `x` in the bipartite `compatible` relation.
+fn pair_up(x: usize, compatible: &[Vec<bool>], seen: &mut [bool], owner: &mut [usize]) -> bool {
+    for y in 0..owner.len() {
+        if compatible[x][y] && !seen[y] {
+            seen[y] = true;
+
+            if owner[y] == NONE || pair_up(owner[y], compatible, seen, owner) {
+                owner[y] = x;
+                return true;
+            }
+        }
+    }
+
+    false
+}
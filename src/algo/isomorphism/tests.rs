@@ -0,0 +1,140 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{
+    stable_graph::StableGraph, traits::Neighbors, unstable_graph::*, Cyclic, Directed, Undirected,
+};
+
+fn directed_cycle(weights: &[u8]) -> UnstableGraph<u8, (), Directed, Cyclic> {
+    let mut graph = UnstableGraph::<_, _, _, Cyclic>::directed();
+    let vertices = weights
+        .iter()
+        .map(|&weight| graph.insert_vertex(weight).unwrap())
+        .collect::<Vec<_>>();
+
+    for i in 0..vertices.len() {
+        graph
+            .insert_edge(vertices[i], vertices[(i + 1) % vertices.len()], ())
+            .unwrap();
+    }
+
+    graph
+}
+
+#[test]
+fn isomorphism_respects_direction_and_weights() {
+    let a = directed_cycle(&[1, 2, 3, 4]);
+    let b = directed_cycle(&[3, 4, 1, 2]);
+    let c = directed_cycle(&[4, 3, 2, 1]);
+
+    assert!(is_isomorphic(&a, &b));
+    assert!(is_isomorphic(&a, &c));
+    assert!(is_isomorphic_matching(&a, &b, |x, y| x == y, |_, _| true));
+    assert!(!is_isomorphic_matching(&a, &c, |x, y| x == y, |_, _| true));
+
+    let mut undirected = UnstableGraph::<u8, (), Undirected, Cyclic>::undirected();
+    let vertices = (0..4)
+        .map(|i| undirected.insert_vertex(i).unwrap())
+        .collect::<Vec<_>>();
+    for i in 0..4 {
+        undirected
+            .insert_edge(vertices[i], vertices[(i + 1) % 4], ())
+            .unwrap();
+    }
+
+    assert!(!is_isomorphic(&a, &undirected));
+}
+
+#[test]
+fn subgraph_isomorphisms_are_induced() {
+    // A triangle with a tail, searched for a path of two edges.
+    let mut target = StableGraph::<char, (), Undirected, Cyclic>::new_undirected();
+    let v = ['a', 'b', 'c', 'd'].map(|name| target.insert_vertex(name));
+    for (x, y) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+        target.insert_edge(v[x], v[y], ()).unwrap();
+    }
+
+    let mut pattern = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let p = (0..3)
+        .map(|_| pattern.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+    pattern.insert_edge(p[0], p[1], ()).unwrap();
+    pattern.insert_edge(p[1], p[2], ()).unwrap();
+
+    let mappings =
+        subgraph_isomorphisms_iter(&pattern, &target, |_, _| true, |_, _| true).collect::<Vec<_>>();
+
+    // Only the paths through `c` into the tail are induced; paths around the triangle are not.
+    assert_eq!(mappings.len(), 4);
+    for mapping in &mappings {
+        assert_eq!(mapping[&p[1]], v[2]);
+        assert!(mapping[&p[0]] == v[3] || mapping[&p[2]] == v[3]);
+    }
+}
+
+#[test]
+fn subgraph_monomorphisms_allow_extra_edges() {
+    let mut target = StableGraph::<char, (), Undirected, Cyclic>::new_undirected();
+    let v = ['a', 'b', 'c', 'd'].map(|name| target.insert_vertex(name));
+    for (x, y) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+        target.insert_edge(v[x], v[y], ()).unwrap();
+    }
+
+    let mut pattern = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let p = (0..3)
+        .map(|_| pattern.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+    pattern.insert_edge(p[0], p[1], ()).unwrap();
+    pattern.insert_edge(p[1], p[2], ()).unwrap();
+
+    // Every path of two edges counts, including the six around the triangle.
+    let mappings = subgraph_monomorphisms_iter(&pattern, &target, |_, _| true, |_, _| true)
+        .collect::<Vec<_>>();
+    assert_eq!(mappings.len(), 10);
+    for mapping in &mappings {
+        assert!(target
+            .neighbors(mapping[&p[1]])
+            .any(|n| n == mapping[&p[0]]));
+        assert!(target
+            .neighbors(mapping[&p[1]])
+            .any(|n| n == mapping[&p[2]]));
+    }
+
+    // Two vertices without an edge only occur as an induced subgraph between `a` or `b` and `d`.
+    let mut pair = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    pair.insert_vertex(()).unwrap();
+    pair.insert_vertex(()).unwrap();
+    assert_eq!(
+        subgraph_isomorphisms_iter(&pair, &target, |_, _| true, |_, _| true).count(),
+        4
+    );
+    assert_eq!(
+        subgraph_monomorphisms_iter(&pair, &target, |_, _| true, |_, _| true).count(),
+        12
+    );
+}
+
+#[test]
+fn subgraph_monomorphisms_respect_direction() {
+    let mut target = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let [x, y, z] = [(); 3].map(|_| target.insert_vertex(()).unwrap());
+    target.insert_edge(x, y, ()).unwrap();
+    target.insert_edge(y, x, ()).unwrap();
+    target.insert_edge(y, z, ()).unwrap();
+
+    let mut pattern = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let [p0, p1] = [(); 2].map(|_| pattern.insert_vertex(()).unwrap());
+    pattern.insert_edge(p0, p1, ()).unwrap();
+
+    let mut induced = subgraph_isomorphisms_iter(&pattern, &target, |_, _| true, |_, _| true)
+        .map(|mapping| (mapping[&p0], mapping[&p1]))
+        .collect::<Vec<_>>();
+    induced.sort();
+    assert_eq!(induced, [(y, z)]);
+
+    let mut mono = subgraph_monomorphisms_iter(&pattern, &target, |_, _| true, |_, _| true)
+        .map(|mapping| (mapping[&p0], mapping[&p1]))
+        .collect::<Vec<_>>();
+    mono.sort();
+    assert_eq!(mono, [(x, y), (y, x), (y, z)]);
+}
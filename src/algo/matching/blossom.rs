@@ -0,0 +1,651 @@
+//! Edmonds' weighted blossom algorithm, following Van Rantwijk's formulation of the `O(n³)`
+//! primal-dual method described in Galil, "Efficient algorithms for finding maximum matching in
+//! graphs", ACM Computing Surveys, 1986.
+//!
+//! Edges are numbered `k` and their endpoints `2k` and `2k + 1`, so that `p ^ 1` is the opposite
+//! end of the edge that endpoint `p` belongs to. Vertices are numbered `0..n` and non-trivial
+//! blossoms `n..2n`.
+
+const NONE: usize = usize::MAX;
+
+/// A label marking a blossom as an outer (`S`) vertex.
+const OUTER: u8 = 1;
+/// A label marking a blossom as an inner (`T`) vertex.
+const INNER: u8 = 2;
+/// A temporary mark used while scanning for the base of a new blossom.
+const BREADCRUMB: u8 = 4;
+
+/// Computes a maximum weight matching of the vertices `0..vertex_count` joined by `edges`, each
+/// given as `(u, v, weight)` with `u != v`, and returns the indices of the matched edges.
+pub(super) fn maximum_weight_matching(
+    vertex_count: usize,
+    edges: Vec<(usize, usize, i64)>,
+) -> Vec<usize> {
+    if edges.is_empty() {
+        return vec![];
+    }
+
+    let mut matcher = Matcher::new(vertex_count, edges);
+    matcher.solve();
+
+    (0..vertex_count)
+        .filter_map(|vertex| match matcher.mate[vertex] {
+            NONE => None,
+            end if matcher.endpoint[end] > vertex => Some(end / 2),
+            _ => None,
+        })
+        .collect()
+}
+
+struct Matcher {
+    vertex_count: usize,
+    edges: Vec<(usize, usize, i64)>,
+    /// The vertex at each edge endpoint.
+    endpoint: Vec<usize>,
+    /// The remote endpoints of the edges incident to each vertex.
+    neighbour_ends: Vec<Vec<usize>>,
+    /// The remote endpoint of each vertex's matched edge, or `NONE` if it is single.
+    mate: Vec<usize>,
+    /// The label of each top-level blossom and of each vertex, where `0` is unlabelled.
+    label: Vec<u8>,
+    /// The endpoint through which each labelled blossom was reached.
+    label_end: Vec<usize>,
+    /// The top-level blossom containing each vertex.
+    in_blossom: Vec<usize>,
+    blossom_parent: Vec<usize>,
+    /// The sub-blossoms of each blossom, in cyclic order starting from its base.
+    blossom_children: Vec<Vec<usize>>,
+    blossom_base: Vec<usize>,
+    /// The endpoints joining consecutive sub-blossoms of each blossom.
+    blossom_endpoints: Vec<Vec<usize>>,
+    /// The least-slack edge from each unlabelled vertex or outer blossom to an outer blossom.
+    best_edge: Vec<usize>,
+    /// The least-slack edges from each outer blossom to each other outer blossom.
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    /// The dual variables of the vertices, followed by those of the blossoms.
+    dual: Vec<i64>,
+    /// Whether each edge has zero slack and may be used in an alternating tree.
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl Matcher {
+    fn new(vertex_count: usize, edges: Vec<(usize, usize, i64)>) -> Self {
+        let max_weight = edges.iter().map(|&(_, _, w)| w).max().unwrap_or(0).max(0);
+
+        let mut endpoint = Vec::with_capacity(2 * edges.len());
+        let mut neighbour_ends = vec![vec![]; vertex_count];
+
+        for (k, &(i, j, _)) in edges.iter().enumerate() {
+            endpoint.extend([i, j]);
+            neighbour_ends[i].push(2 * k + 1);
+            neighbour_ends[j].push(2 * k);
+        }
+
+        let mut dual = vec![max_weight; vertex_count];
+        dual.resize(2 * vertex_count, 0);
+
+        let mut blossom_base = (0..vertex_count).collect::<Vec<_>>();
+        blossom_base.resize(2 * vertex_count, NONE);
+
+        Matcher {
+            vertex_count,
+            endpoint,
+            neighbour_ends,
+            mate: vec![NONE; vertex_count],
+            label: vec![0; 2 * vertex_count],
+            label_end: vec![NONE; 2 * vertex_count],
+            in_blossom: (0..vertex_count).collect(),
+            blossom_parent: vec![NONE; 2 * vertex_count],
+            blossom_children: vec![vec![]; 2 * vertex_count],
+            blossom_base,
+            blossom_endpoints: vec![vec![]; 2 * vertex_count],
+            best_edge: vec![NONE; 2 * vertex_count],
+            blossom_best_edges: vec![None; 2 * vertex_count],
+            unused_blossoms: (vertex_count..2 * vertex_count).collect(),
+            dual,
+            allowed: vec![false; edges.len()],
+            queue: vec![],
+            edges,
+        }
+    }
+
+    #[inline]
+    fn slack(&self, k: usize) -> i64 {
+        let (i, j, w) = self.edges[k];
+        self.dual[i] + self.dual[j] - 2 * w
+    }
+
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = vec![];
+        let mut pending = vec![b];
+
+        while let Some(b) = pending.pop() {
+            if b < self.vertex_count {
+                leaves.push(b);
+            } else {
+                pending.extend(self.blossom_children[b].iter().rev());
+            }
+        }
+
+        leaves
+    }
+
+    /// Labels the top-level blossom containing `w` with `label`, having reached it through the
+    /// endpoint `p`. An inner blossom's mate is labelled outer in turn.
+    fn assign_label(&mut self, w: usize, label: u8, p: usize) {
+        let b = self.in_blossom[w];
+
+        self.label[w] = label;
+        self.label[b] = label;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = NONE;
+        self.best_edge[b] = NONE;
+
+        if label == OUTER {
+            let leaves = self.leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            let base_mate = self.mate[self.blossom_base[b]];
+            self.assign_label(self.endpoint[base_mate], OUTER, base_mate ^ 1);
+        }
+    }
+
+    /// Traces back from the outer vertices `v` and `w` and returns the base of the new blossom
+    /// that their alternating paths meet at, or `NONE` if they reach distinct roots.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = vec![];
+        let mut base = NONE;
+
+        while v != NONE || w != NONE {
+            let b = self.in_blossom[v];
+
+            if self.label[b] & BREADCRUMB != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+
+            path.push(b);
+            self.label[b] = OUTER | BREADCRUMB;
+
+            if self.label_end[b] == NONE {
+                v = NONE;
+            } else {
+                let t = self.in_blossom[self.endpoint[self.label_end[b]]];
+                v = self.endpoint[self.label_end[t]];
+            }
+
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+
+        for b in path {
+            self.label[b] = OUTER;
+        }
+
+        base
+    }
+
+    /// Contracts the odd cycle closed by edge `k` into a new blossom with the given base.
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+
+        let b = self.unused_blossoms.pop().expect("a blossom is available");
+        self.blossom_base[b] = base;
+        self.blossom_parent[b] = NONE;
+        self.blossom_parent[bb] = b;
+
+        let mut children = vec![];
+        let mut endpoints = vec![];
+
+        while bv != bb {
+            self.blossom_parent[bv] = b;
+            children.push(bv);
+            endpoints.push(self.label_end[bv]);
+            v = self.endpoint[self.label_end[bv]];
+            bv = self.in_blossom[v];
+        }
+
+        children.push(bb);
+        children.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+
+        while bw != bb {
+            self.blossom_parent[bw] = b;
+            children.push(bw);
+            endpoints.push(self.label_end[bw] ^ 1);
+            w = self.endpoint[self.label_end[bw]];
+            bw = self.in_blossom[w];
+        }
+
+        self.label[b] = OUTER;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = 0;
+
+        for leaf in self.leaves_of(&children) {
+            if self.label[self.in_blossom[leaf]] == INNER {
+                self.queue.push(leaf);
+            }
+            self.in_blossom[leaf] = b;
+        }
+
+        let mut best_edge_to = vec![NONE; 2 * self.vertex_count];
+
+        for &child in &children {
+            let edge_lists = match self.blossom_best_edges[child].take() {
+                Some(edges) => vec![edges],
+                None => self
+                    .leaves(child)
+                    .into_iter()
+                    .map(|leaf| self.neighbour_ends[leaf].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+
+            for edge in edge_lists.into_iter().flatten() {
+                let (i, j, _) = self.edges[edge];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+
+                if bj != b
+                    && self.label[bj] == OUTER
+                    && (best_edge_to[bj] == NONE || self.slack(edge) < self.slack(best_edge_to[bj]))
+                {
+                    best_edge_to[bj] = edge;
+                }
+            }
+
+            self.best_edge[child] = NONE;
+        }
+
+        let best_edges = best_edge_to
+            .into_iter()
+            .filter(|&edge| edge != NONE)
+            .collect::<Vec<_>>();
+
+        self.best_edge[b] = best_edges
+            .iter()
+            .copied()
+            .min_by_key(|&edge| self.slack(edge))
+            .unwrap_or(NONE);
+        self.blossom_best_edges[b] = Some(best_edges);
+        self.blossom_children[b] = children;
+        self.blossom_endpoints[b] = endpoints;
+    }
+
+    fn leaves_of(&self, blossoms: &[usize]) -> Vec<usize> {
+        blossoms.iter().flat_map(|&b| self.leaves(b)).collect()
+    }
+
+    /// Expands the blossom `b` into its sub-blossoms, relabelling them if `b` was an inner
+    /// blossom in the middle of a stage.
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for child in self.blossom_children[b].clone() {
+            self.blossom_parent[child] = NONE;
+
+            if child < self.vertex_count {
+                self.in_blossom[child] = child;
+            } else if end_stage && self.dual[child] == 0 {
+                self.expand_blossom(child, end_stage);
+            } else {
+                for leaf in self.leaves(child) {
+                    self.in_blossom[leaf] = child;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == INNER {
+            self.relabel_expanded(b);
+        }
+
+        self.label[b] = 0;
+        self.label_end[b] = NONE;
+        self.blossom_children[b] = vec![];
+        self.blossom_endpoints[b] = vec![];
+        self.blossom_base[b] = NONE;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Relabels the sub-blossoms of the inner blossom `b` that lie on the alternating path from
+    /// the sub-blossom it was entered through to its base.
+    fn relabel_expanded(&mut self, b: usize) {
+        let children = self.blossom_children[b].clone();
+        let endpoints = self.blossom_endpoints[b].clone();
+        let len = children.len() as isize;
+        let at = |j: isize| j.rem_euclid(len) as usize;
+
+        let entry_child = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+        let mut j = children.iter().position(|&c| c == entry_child).unwrap() as isize;
+
+        let (step, trick) = if j & 1 == 1 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+
+        let mut p = self.label_end[b];
+
+        while j != 0 {
+            let q = endpoints[at(j - trick)] ^ trick as usize ^ 1;
+
+            self.label[self.endpoint[p ^ 1]] = 0;
+            self.label[self.endpoint[q]] = 0;
+            self.assign_label(self.endpoint[p ^ 1], INNER, p);
+            self.allowed[endpoints[at(j - trick)] / 2] = true;
+
+            j += step;
+            p = endpoints[at(j - trick)] ^ trick as usize;
+            self.allowed[p / 2] = true;
+            j += step;
+        }
+
+        let bv = children[at(j)];
+        let end = self.endpoint[p ^ 1];
+        self.label[end] = INNER;
+        self.label[bv] = INNER;
+        self.label_end[end] = p;
+        self.label_end[bv] = p;
+        self.best_edge[bv] = NONE;
+        j += step;
+
+        while children[at(j)] != entry_child {
+            let bv = children[at(j)];
+            j += step;
+
+            if self.label[bv] == OUTER {
+                continue;
+            }
+
+            if let Some(v) = self
+                .leaves(bv)
+                .into_iter()
+                .find(|&leaf| self.label[leaf] != 0)
+            {
+                self.label[v] = 0;
+                self.label[self.endpoint[self.mate[self.blossom_base[bv]]]] = 0;
+                self.assign_label(v, INNER, self.label_end[v]);
+            }
+        }
+    }
+
+    /// Swaps the matched and unmatched edges along the even-length path through blossom `b`
+    /// from vertex `v` to its base, making `v` the new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossom_parent[t] != b {
+            t = self.blossom_parent[t];
+        }
+
+        if t >= self.vertex_count {
+            self.augment_blossom(t, v);
+        }
+
+        let len = self.blossom_children[b].len() as isize;
+        let at = |j: isize| j.rem_euclid(len) as usize;
+
+        let i = self.blossom_children[b]
+            .iter()
+            .position(|&c| c == t)
+            .unwrap();
+        let mut j = i as isize;
+
+        let (step, trick) = if i & 1 == 1 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+
+        while j != 0 {
+            j += step;
+            let t = self.blossom_children[b][at(j)];
+            let p = self.blossom_endpoints[b][at(j - trick)] ^ trick as usize;
+
+            if t >= self.vertex_count {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+
+            j += step;
+            let t = self.blossom_children[b][at(j)];
+
+            if t >= self.vertex_count {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+
+        self.blossom_children[b].rotate_left(i);
+        self.blossom_endpoints[b].rotate_left(i);
+        self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+    }
+
+    /// Swaps the matched and unmatched edges along the augmenting path through edge `k`.
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+
+                if bs >= self.vertex_count {
+                    self.augment_blossom(bs, s);
+                }
+
+                self.mate[s] = p;
+
+                if self.label_end[bs] == NONE {
+                    break;
+                }
+
+                let t = self.endpoint[self.label_end[bs]];
+                let bt = self.in_blossom[t];
+                s = self.endpoint[self.label_end[bt]];
+                let j = self.endpoint[self.label_end[bt] ^ 1];
+
+                if bt >= self.vertex_count {
+                    self.augment_blossom(bt, j);
+                }
+
+                self.mate[j] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+
+    fn solve(&mut self) {
+        let n = self.vertex_count;
+
+        for _ in 0..n {
+            self.label.fill(0);
+            self.best_edge.fill(NONE);
+            self.blossom_best_edges[n..].fill(None);
+            self.allowed.fill(false);
+            self.queue.clear();
+
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, OUTER, NONE);
+                }
+            }
+
+            let mut augmented = false;
+
+            loop {
+                while !augmented {
+                    let Some(v) = self.queue.pop() else {
+                        break;
+                    };
+                    augmented = self.scan(v);
+                }
+
+                if augmented || !self.adjust_duals() {
+                    break;
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+
+            for b in n..2 * n {
+                if self.blossom_parent[b] == NONE
+                    && self.blossom_base[b] != NONE
+                    && self.label[b] == OUTER
+                    && self.dual[b] == 0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+
+    /// Grows the alternating forest along the edges of the outer vertex `v` and returns `true` if
+    /// an augmenting path was found and applied.
+    fn scan(&mut self, v: usize) -> bool {
+        for p in self.neighbour_ends[v].clone() {
+            let k = p / 2;
+            let w = self.endpoint[p];
+
+            if self.in_blossom[v] == self.in_blossom[w] {
+                continue;
+            }
+
+            let mut slack = 0;
+            if !self.allowed[k] {
+                slack = self.slack(k);
+                if slack <= 0 {
+                    self.allowed[k] = true;
+                }
+            }
+
+            let bw = self.in_blossom[w];
+
+            if self.allowed[k] {
+                if self.label[bw] == 0 {
+                    self.assign_label(w, INNER, p ^ 1);
+                } else if self.label[bw] == OUTER {
+                    match self.scan_blossom(v, w) {
+                        NONE => {
+                            self.augment_matching(k);
+                            return true;
+                        }
+                        base => self.add_blossom(base, k),
+                    }
+                } else if self.label[w] == 0 {
+                    self.label[w] = INNER;
+                    self.label_end[w] = p ^ 1;
+                }
+            } else if self.label[bw] == OUTER {
+                let b = self.in_blossom[v];
+                if self.best_edge[b] == NONE || slack < self.slack(self.best_edge[b]) {
+                    self.best_edge[b] = k;
+                }
+            } else if self.label[w] == 0
+                && (self.best_edge[w] == NONE || slack < self.slack(self.best_edge[w]))
+            {
+                self.best_edge[w] = k;
+            }
+        }
+
+        false
+    }
+
+    /// Adjusts the dual variables by the largest amount that keeps them feasible, making a new
+    /// edge usable or expanding a blossom. Returns `false` if the duals have reached the point
+    /// where no further augmentation is possible.
+    fn adjust_duals(&mut self) -> bool {
+        let n = self.vertex_count;
+
+        enum Delta {
+            Optimum,
+            Edge(usize),
+            OuterEdge(usize),
+            Blossom(usize),
+        }
+
+        let mut delta = *self.dual[..n].iter().min().unwrap();
+        let mut kind = Delta::Optimum;
+
+        for v in 0..n {
+            if self.label[self.in_blossom[v]] == 0 && self.best_edge[v] != NONE {
+                let d = self.slack(self.best_edge[v]);
+                if d < delta {
+                    delta = d;
+                    kind = Delta::Edge(self.best_edge[v]);
+                }
+            }
+        }
+
+        for b in 0..2 * n {
+            if self.blossom_parent[b] == NONE && self.label[b] == OUTER && self.best_edge[b] != NONE
+            {
+                let d = self.slack(self.best_edge[b]) / 2;
+                if d < delta {
+                    delta = d;
+                    kind = Delta::OuterEdge(self.best_edge[b]);
+                }
+            }
+        }
+
+        for b in n..2 * n {
+            if self.blossom_base[b] != NONE
+                && self.blossom_parent[b] == NONE
+                && self.label[b] == INNER
+                && self.dual[b] < delta
+            {
+                delta = self.dual[b];
+                kind = Delta::Blossom(b);
+            }
+        }
+
+        for v in 0..n {
+            match self.label[self.in_blossom[v]] {
+                OUTER => self.dual[v] -= delta,
+                INNER => self.dual[v] += delta,
+                _ => {}
+            }
+        }
+
+        for b in n..2 * n {
+            if self.blossom_base[b] != NONE && self.blossom_parent[b] == NONE {
+                match self.label[b] {
+                    OUTER => self.dual[b] += delta,
+                    INNER => self.dual[b] -= delta,
+                    _ => {}
+                }
+            }
+        }
+
+        match kind {
+            Delta::Optimum => return false,
+            Delta::Edge(k) => {
+                self.allowed[k] = true;
+                let (i, j, _) = self.edges[k];
+                let i = if self.label[self.in_blossom[i]] == 0 {
+                    j
+                } else {
+                    i
+                };
+                self.queue.push(i);
+            }
+            Delta::OuterEdge(k) => {
+                self.allowed[k] = true;
+                self.queue.push(self.edges[k].0);
+            }
+            Delta::Blossom(b) => self.expand_blossom(b, false),
+        }
+
+        true
+    }
+}
@@ -0,0 +1,172 @@
+mod blossom;
+mod tests;
+
+use super::indexed::Indexed;
+use crate::graph::traits::Graph;
+use std::collections::{HashSet, VecDeque};
+
+/// Finds a maximum cardinality matching of a general graph using Edmonds'
+/// [blossom](https://en.wikipedia.org/wiki/Blossom_algorithm) algorithm and returns the matched
+/// edges. Edge direction and self-loops are ignored.
+pub fn maximum_matching<G: Graph + ?Sized>(graph: &G) -> Vec<G::EdgeId> {
+    maximum_weight_matching(graph, |_| 1)
+}
+
+/// Finds a matching of a general graph whose total edge weight, as given by `weight`, is as large
+/// as possible using Edmonds' blossom algorithm and returns the matched edges. Edges of
+/// non-positive weight are never matched. Edge direction and self-loops are ignored.
+///
+/// Weights are integers because the algorithm's dual variables are kept exact by halving even
+/// quantities; fractional weights can be scaled up to integers.
+pub fn maximum_weight_matching<G, F>(graph: &G, mut weight: F) -> Vec<G::EdgeId>
+where
+    G: Graph + ?Sized,
+    F: FnMut(&G::EdgeWeight) -> i64,
+{
+    let indexed = Indexed::new(graph);
+
+    let (ids, edges): (Vec<_>, Vec<_>) = indexed
+        .edges
+        .iter()
+        .filter(|edge| edge.source != edge.target)
+        .map(|edge| (edge.id, (edge.source, edge.target, weight(edge.weight))))
+        .unzip();
+
+    blossom::maximum_weight_matching(indexed.vertex_count(), edges)
+        .into_iter()
+        .map(|edge| ids[edge])
+        .collect()
+}
+
+/// Finds a maximum matching of a bipartite graph using the
+/// [Hopcroft–Karp](https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm) algorithm and
+/// returns the matched edges.
+///
+/// The vertices in `left` form one side of the partition and every other vertex forms the other
+/// side; edges that do not cross the partition are ignored, as is edge direction. A suitable
+/// partition can be found with [`is_bipartite`][`crate::algo::bipartite::is_bipartite`].
+pub fn hopcroft_karp<G: Graph + ?Sized>(graph: &G, left: &HashSet<G::VertexId>) -> Vec<G::EdgeId> {
+    let indexed = Indexed::new(graph);
+    let count = indexed.vertex_count();
+
+    let is_left = indexed
+        .vertices
+        .iter()
+        .map(|vertex| left.contains(vertex))
+        .collect::<Vec<_>>();
+
+    let adjacent = (0..count)
+        .map(|vertex| match is_left[vertex] {
+            true => indexed.adjacent[vertex]
+                .iter()
+                .copied()
+                .filter(|&(neighbour, _)| !is_left[neighbour])
+                .collect(),
+            false => vec![],
+        })
+        .collect::<Vec<Vec<_>>>();
+
+    let mut matcher = HopcroftKarp {
+        adjacent: &adjacent,
+        left_mate: vec![None; count],
+        right_mate: vec![None; count],
+        distance: vec![usize::MAX; count],
+        cursor: vec![0; count],
+    };
+
+    while matcher.layer(&is_left) {
+        matcher.cursor.fill(0);
+
+        for (vertex, &is_left) in is_left.iter().enumerate() {
+            if is_left && matcher.left_mate[vertex].is_none() {
+                matcher.augment(vertex);
+            }
+        }
+    }
+
+    matcher
+        .left_mate
+        .into_iter()
+        .flatten()
+        .map(|(_, edge)| indexed.edges[edge].id)
+        .collect()
+}
+
+struct HopcroftKarp<'a> {
+    adjacent: &'a [Vec<(usize, usize)>],
+    left_mate: Vec<Option<(usize, usize)>>,
+    right_mate: Vec<Option<usize>>,
+    distance: Vec<usize>,
+    cursor: Vec<usize>,
+}
+
+impl HopcroftKarp<'_> {
+    /// Layers the left vertices by alternating path distance from the free left vertices and
+    /// returns `true` if a free right vertex is reachable.
+    fn layer(&mut self, is_left: &[bool]) -> bool {
+        let mut queue = VecDeque::new();
+        let mut found = false;
+
+        for (vertex, &is_left) in is_left.iter().enumerate() {
+            self.distance[vertex] = usize::MAX;
+
+            if is_left && self.left_mate[vertex].is_none() {
+                self.distance[vertex] = 0;
+                queue.push_back(vertex);
+            }
+        }
+
+        while let Some(vertex) = queue.pop_front() {
+            for &(neighbour, _) in &self.adjacent[vertex] {
+                match self.right_mate[neighbour] {
+                    None => found = true,
+                    Some(mate) if self.distance[mate] == usize::MAX => {
+                        self.distance[mate] = self.distance[vertex] + 1;
+                        queue.push_back(mate);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Searches for an augmenting path from the free left vertex `root` along the layering and
+    /// flips it if one is found.
+    fn augment(&mut self, root: usize) -> bool {
+        let mut path = vec![root];
+        let mut steps: Vec<(usize, usize)> = vec![];
+
+        while let Some(&vertex) = path.last() {
+            let Some(&(neighbour, edge)) = self.adjacent[vertex].get(self.cursor[vertex]) else {
+                self.distance[vertex] = usize::MAX;
+                path.pop();
+                steps.pop();
+                continue;
+            };
+
+            self.cursor[vertex] += 1;
+
+            match self.right_mate[neighbour] {
+                None => {
+                    steps.push((neighbour, edge));
+
+                    for (&left, &(right, edge)) in path.iter().zip(&steps) {
+                        self.left_mate[left] = Some((right, edge));
+                        self.right_mate[right] = Some(left);
+                    }
+
+                    return true;
+                }
+                Some(mate) if self.distance[mate] == self.distance[vertex] + 1 => {
+                    steps.push((neighbour, edge));
+                    path.push(mate);
+                }
+                Some(_) => {}
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    algo::bipartite::is_bipartite,
+    graph::{stable_graph::StableGraph, unstable_graph::*, Cyclic, Undirected},
+};
+
+#[test]
+fn hopcroft_karp_finds_perfect_matching() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let people = (0..4)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+    let tasks = (0..4)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    // A greedy matching that pairs each person with their first task gets stuck at three.
+    for (person, options) in [
+        (0, vec![0, 1]),
+        (1, vec![0]),
+        (2, vec![1, 2]),
+        (3, vec![2, 3]),
+    ] {
+        for task in options {
+            graph.insert_edge(people[person], tasks[task], ()).unwrap();
+        }
+    }
+
+    let left = is_bipartite(&graph).unwrap().left;
+    let matching = hopcroft_karp(&graph, &left);
+
+    assert_eq!(matching.len(), 4);
+
+    let mut covered = HashSet::new();
+    for edge in matching {
+        let (a, b) = graph.edges[edge.index()].vertex_indices();
+        assert!(covered.insert(a) && covered.insert(b));
+    }
+}
+
+#[test]
+fn hopcroft_karp_ignores_edges_within_a_side() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let a = graph.insert_vertex(()).unwrap();
+    let b = graph.insert_vertex(()).unwrap();
+    let c = graph.insert_vertex(()).unwrap();
+
+    graph.insert_edge(a, b, ()).unwrap();
+    let bc = graph.insert_edge(b, c, ()).unwrap();
+
+    let matching = hopcroft_karp(&graph, &HashSet::from([a, b]));
+
+    assert_eq!(matching, vec![bc]);
+}
+
+#[test]
+fn blossom_matches_odd_cycles() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = (0..6)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    // Two triangles joined by a bridge; each triangle has a vertex left over, so pairing everything
+    // requires matching across the bridge.
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    let matching = maximum_matching(&graph);
+
+    assert_eq!(matching.len(), 3);
+    assert!(matching.contains(&EdgeIndex::new(3)));
+}
+
+#[test]
+fn blossom_maximises_weight() {
+    let mut graph = StableGraph::<(), i64, Undirected, Cyclic>::new_undirected();
+    let v = (0..4).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    let a = graph.insert_edge(v[0], v[1], 5).unwrap();
+    let b = graph.insert_edge(v[1], v[2], 11).unwrap();
+    let c = graph.insert_edge(v[2], v[3], 5).unwrap();
+
+    assert_eq!(maximum_weight_matching(&graph, |&w| w), vec![b]);
+    assert_eq!(maximum_weight_matching(&graph, |&w| w - 4).len(), 1);
+
+    let mut matching = maximum_weight_matching(&graph, |&w| w.min(6));
+    matching.sort();
+    let mut expected = vec![a, c];
+    expected.sort();
+
+    assert_eq!(matching, expected);
+}
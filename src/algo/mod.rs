@@ -0,0 +1,25 @@
+mod indexed;
+mod rng;
+
+/// Minimum spanning arborescences.
+pub mod arborescence;
+/// Bipartiteness testing.
+pub mod bipartite;
+/// Vertex and edge centrality measures.
+pub mod centrality;
+/// Clique enumeration.
+pub mod clique;
+/// Triangle counting and clustering coefficients.
+pub mod clustering;
+/// Vertex colouring and register allocation.
+pub mod colouring;
+/// Community detection.
+pub mod community;
+/// Core decomposition.
+pub mod cores;
+/// Eulerian paths and circuits.
+pub mod euler;
+/// Graph isomorphism, and subgraph isomorphism and monomorphism.
+pub mod isomorphism;
+/// Maximum matchings.
+pub mod matching;
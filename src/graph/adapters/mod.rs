@@ -0,0 +1,637 @@
+mod tests;
+
+use super::{
+    traits::{
+        Counts, Data, EdgeEndpoints, EdgeIds, GraphBase, GraphProp, IncidentEdges, Neighbors,
+        VertexIds, Visitable,
+    },
+    Direction, EdgeType, Undirected,
+};
+
+/// A view of a graph in which every edge points the other way. Incoming and outgoing edges trade
+/// places and the endpoints of every edge are swapped, so an algorithm that follows edges
+/// forwards follows them backwards in the underlying graph.
+#[derive(Debug)]
+pub struct Reversed<'g, G: ?Sized> {
+    graph: &'g G,
+}
+
+impl<'g, G: ?Sized> Reversed<'g, G> {
+    /// Creates a reversed view of `graph`.
+    #[inline]
+    pub fn new(graph: &'g G) -> Self {
+        Reversed { graph }
+    }
+
+    /// Returns the graph that this view reverses.
+    #[inline]
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+}
+
+impl<G: ?Sized> Clone for Reversed<'_, G> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G: ?Sized> Copy for Reversed<'_, G> {}
+
+impl<G: GraphBase + ?Sized> GraphBase for Reversed<'_, G> {
+    type VertexId = G::VertexId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphProp + ?Sized> GraphProp for Reversed<'_, G> {
+    type EdgeType = G::EdgeType;
+}
+
+impl<G: Data + ?Sized> Data for Reversed<'_, G> {
+    type VertexWeight = G::VertexWeight;
+    type EdgeWeight = G::EdgeWeight;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: G::VertexId) -> Option<&G::VertexWeight> {
+        self.graph.vertex_weight(vertex)
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: G::EdgeId) -> Option<&G::EdgeWeight> {
+        self.graph.edge_weight(edge)
+    }
+}
+
+impl<G: Counts + ?Sized> Counts for Reversed<'_, G> {
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.graph.vertex_count()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+}
+
+impl<G: VertexIds + ?Sized> VertexIds for Reversed<'_, G> {
+    type VertexIds<'a>
+        = G::VertexIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        self.graph.vertex_ids()
+    }
+}
+
+impl<G: EdgeIds + ?Sized> EdgeIds for Reversed<'_, G> {
+    type EdgeIds<'a>
+        = G::EdgeIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> Self::EdgeIds<'_> {
+        self.graph.edge_ids()
+    }
+}
+
+impl<G: EdgeEndpoints + ?Sized> EdgeEndpoints for Reversed<'_, G> {
+    #[inline]
+    fn edge_endpoints(&self, edge: G::EdgeId) -> Option<(G::VertexId, G::VertexId)> {
+        self.graph
+            .edge_endpoints(edge)
+            .map(|(source, target)| (target, source))
+    }
+}
+
+impl<G: Neighbors + ?Sized> Neighbors for Reversed<'_, G> {
+    type Neighbors<'a>
+        = G::Neighbors<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn neighbors_directed(&self, vertex: G::VertexId, direction: Direction) -> Self::Neighbors<'_> {
+        self.graph.neighbors_directed(vertex, direction.opposite())
+    }
+}
+
+impl<G: IncidentEdges + ?Sized> IncidentEdges for Reversed<'_, G> {
+    type Edges<'a>
+        = G::Edges<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edges_directed(&self, vertex: G::VertexId, direction: Direction) -> Self::Edges<'_> {
+        self.graph.edges_directed(vertex, direction.opposite())
+    }
+}
+
+impl<G: Visitable + ?Sized> Visitable for Reversed<'_, G> {
+    type Map = G::Map;
+
+    #[inline]
+    fn visit_map(&self) -> G::Map {
+        self.graph.visit_map()
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut G::Map) {
+        self.graph.reset_map(map);
+    }
+}
+
+/// A view of a graph that hides the vertices and edges that its predicates reject. An edge is
+/// only kept if both of its endpoints are, and a hidden vertex has no neighbours.
+///
+/// Nothing is cached, so counting the vertices or edges of a [`Filtered`] view walks all of
+/// them in the underlying graph.
+pub struct Filtered<'g, G: ?Sized, FV, FE> {
+    graph: &'g G,
+    vertex_filter: FV,
+    edge_filter: FE,
+}
+
+impl<'g, G: GraphBase + ?Sized, FV, FE> Filtered<'g, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    /// Creates a view of `graph` that only keeps the vertices that `vertex_filter` accepts and the
+    /// edges that `edge_filter` accepts.
+    #[inline]
+    pub fn new(graph: &'g G, vertex_filter: FV, edge_filter: FE) -> Self {
+        Filtered {
+            graph,
+            vertex_filter,
+            edge_filter,
+        }
+    }
+
+    /// Returns the graph that this view filters.
+    #[inline]
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+
+    #[inline]
+    fn keeps_vertex(&self, vertex: G::VertexId) -> bool {
+        (self.vertex_filter)(vertex)
+    }
+}
+
+impl<G: EdgeEndpoints + ?Sized, FV, FE> Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    fn endpoints(&self, edge: G::EdgeId) -> Option<(G::VertexId, G::VertexId)> {
+        let (source, target) = self.graph.edge_endpoints(edge)?;
+
+        (self.keeps_vertex(source) && self.keeps_vertex(target) && (self.edge_filter)(edge))
+            .then_some((source, target))
+    }
+}
+
+impl<G: GraphBase + ?Sized, FV, FE> GraphBase for Filtered<'_, G, FV, FE> {
+    type VertexId = G::VertexId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphProp + ?Sized, FV, FE> GraphProp for Filtered<'_, G, FV, FE> {
+    type EdgeType = G::EdgeType;
+}
+
+impl<G: Data + EdgeEndpoints + ?Sized, FV, FE> Data for Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type VertexWeight = G::VertexWeight;
+    type EdgeWeight = G::EdgeWeight;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: G::VertexId) -> Option<&G::VertexWeight> {
+        self.graph
+            .vertex_weight(vertex)
+            .filter(|_| self.keeps_vertex(vertex))
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: G::EdgeId) -> Option<&G::EdgeWeight> {
+        self.endpoints(edge)?;
+        self.graph.edge_weight(edge)
+    }
+}
+
+impl<G: VertexIds + EdgeIds + EdgeEndpoints + ?Sized, FV, FE> Counts for Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.vertex_ids().count()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_ids().count()
+    }
+}
+
+impl<G: VertexIds + ?Sized, FV, FE> VertexIds for Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type VertexIds<'a>
+        = FilteredVertexIds<'a, G, FV, FE>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> FilteredVertexIds<'_, G, FV, FE> {
+        FilteredVertexIds {
+            filtered: self,
+            vertices: self.graph.vertex_ids(),
+        }
+    }
+}
+
+impl<G: EdgeIds + EdgeEndpoints + ?Sized, FV, FE> EdgeIds for Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type EdgeIds<'a>
+        = FilteredEdgeIds<'a, G, FV, FE>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> FilteredEdgeIds<'_, G, FV, FE> {
+        FilteredEdgeIds {
+            filtered: self,
+            edges: self.graph.edge_ids(),
+        }
+    }
+}
+
+impl<G: EdgeEndpoints + ?Sized, FV, FE> EdgeEndpoints for Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    #[inline]
+    fn edge_endpoints(&self, edge: G::EdgeId) -> Option<(G::VertexId, G::VertexId)> {
+        self.endpoints(edge)
+    }
+}
+
+impl<G: IncidentEdges + ?Sized, FV, FE> Neighbors for Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type Neighbors<'a>
+        = FilteredNeighbors<'a, G, FV, FE>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if the underlying graph panics for `vertex`.
+    #[inline]
+    fn neighbors_directed(
+        &self,
+        vertex: G::VertexId,
+        direction: Direction,
+    ) -> FilteredNeighbors<'_, G, FV, FE> {
+        FilteredNeighbors {
+            edges: self.edges_directed(vertex, direction),
+        }
+    }
+}
+
+impl<G: IncidentEdges + ?Sized, FV, FE> IncidentEdges for Filtered<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type Edges<'a>
+        = FilteredEdges<'a, G, FV, FE>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if the underlying graph panics for `vertex`.
+    #[inline]
+    fn edges_directed(
+        &self,
+        vertex: G::VertexId,
+        direction: Direction,
+    ) -> FilteredEdges<'_, G, FV, FE> {
+        FilteredEdges {
+            filtered: self,
+            edges: self
+                .keeps_vertex(vertex)
+                .then(|| self.graph.edges_directed(vertex, direction)),
+        }
+    }
+}
+
+impl<G: Visitable + ?Sized, FV, FE> Visitable for Filtered<'_, G, FV, FE> {
+    type Map = G::Map;
+
+    #[inline]
+    fn visit_map(&self) -> G::Map {
+        self.graph.visit_map()
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut G::Map) {
+        self.graph.reset_map(map);
+    }
+}
+
+/// An iterator over the vertices that a [`Filtered`] view keeps.
+pub struct FilteredVertexIds<'a, G: VertexIds + ?Sized + 'a, FV, FE> {
+    filtered: &'a Filtered<'a, G, FV, FE>,
+    vertices: G::VertexIds<'a>,
+}
+
+impl<G: VertexIds + ?Sized, FV, FE> Iterator for FilteredVertexIds<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type Item = G::VertexId;
+
+    #[inline]
+    fn next(&mut self) -> Option<G::VertexId> {
+        let filtered = self.filtered;
+        self.vertices.find(|&vertex| filtered.keeps_vertex(vertex))
+    }
+}
+
+/// An iterator over the edges that a [`Filtered`] view keeps.
+pub struct FilteredEdgeIds<'a, G: EdgeIds + ?Sized + 'a, FV, FE> {
+    filtered: &'a Filtered<'a, G, FV, FE>,
+    edges: G::EdgeIds<'a>,
+}
+
+impl<G: EdgeIds + EdgeEndpoints + ?Sized, FV, FE> Iterator for FilteredEdgeIds<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type Item = G::EdgeId;
+
+    #[inline]
+    fn next(&mut self) -> Option<G::EdgeId> {
+        let filtered = self.filtered;
+        self.edges.find(|&edge| filtered.endpoints(edge).is_some())
+    }
+}
+
+/// An iterator over the edges of a vertex that a [`Filtered`] view keeps, paired with the vertex
+/// at their other end.
+pub struct FilteredEdges<'a, G: IncidentEdges + ?Sized + 'a, FV, FE> {
+    filtered: &'a Filtered<'a, G, FV, FE>,
+    edges: Option<G::Edges<'a>>,
+}
+
+impl<G: IncidentEdges + ?Sized, FV, FE> Iterator for FilteredEdges<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type Item = (G::EdgeId, G::VertexId);
+
+    #[inline]
+    fn next(&mut self) -> Option<(G::EdgeId, G::VertexId)> {
+        let filtered = self.filtered;
+        self.edges.as_mut()?.find(|&(edge, neighbour)| {
+            filtered.keeps_vertex(neighbour) && (filtered.edge_filter)(edge)
+        })
+    }
+}
+
+/// An iterator over the neighbours of a vertex that a [`Filtered`] view keeps.
+pub struct FilteredNeighbors<'a, G: IncidentEdges + ?Sized + 'a, FV, FE> {
+    edges: FilteredEdges<'a, G, FV, FE>,
+}
+
+impl<G: IncidentEdges + ?Sized, FV, FE> Iterator for FilteredNeighbors<'_, G, FV, FE>
+where
+    FV: Fn(G::VertexId) -> bool,
+    FE: Fn(G::EdgeId) -> bool,
+{
+    type Item = G::VertexId;
+
+    #[inline]
+    fn next(&mut self) -> Option<G::VertexId> {
+        self.edges.next().map(|(_, neighbour)| neighbour)
+    }
+}
+
+/// A view of a graph in which edges have no direction. A vertex's edges and neighbours are the
+/// ones that leave it and the ones that enter it, with each self-loop yielded once.
+#[derive(Debug)]
+pub struct AsUndirected<'g, G: ?Sized> {
+    graph: &'g G,
+}
+
+impl<'g, G: ?Sized> AsUndirected<'g, G> {
+    /// Creates an undirected view of `graph`.
+    #[inline]
+    pub fn new(graph: &'g G) -> Self {
+        AsUndirected { graph }
+    }
+
+    /// Returns the graph that this view ignores the direction of.
+    #[inline]
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+}
+
+impl<G: ?Sized> Clone for AsUndirected<'_, G> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G: ?Sized> Copy for AsUndirected<'_, G> {}
+
+impl<G: GraphBase + ?Sized> GraphBase for AsUndirected<'_, G> {
+    type VertexId = G::VertexId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphBase + ?Sized> GraphProp for AsUndirected<'_, G> {
+    type EdgeType = Undirected;
+}
+
+impl<G: Data + ?Sized> Data for AsUndirected<'_, G> {
+    type VertexWeight = G::VertexWeight;
+    type EdgeWeight = G::EdgeWeight;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: G::VertexId) -> Option<&G::VertexWeight> {
+        self.graph.vertex_weight(vertex)
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: G::EdgeId) -> Option<&G::EdgeWeight> {
+        self.graph.edge_weight(edge)
+    }
+}
+
+impl<G: Counts + ?Sized> Counts for AsUndirected<'_, G> {
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.graph.vertex_count()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+}
+
+impl<G: VertexIds + ?Sized> VertexIds for AsUndirected<'_, G> {
+    type VertexIds<'a>
+        = G::VertexIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        self.graph.vertex_ids()
+    }
+}
+
+impl<G: EdgeIds + ?Sized> EdgeIds for AsUndirected<'_, G> {
+    type EdgeIds<'a>
+        = G::EdgeIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> Self::EdgeIds<'_> {
+        self.graph.edge_ids()
+    }
+}
+
+impl<G: EdgeEndpoints + ?Sized> EdgeEndpoints for AsUndirected<'_, G> {
+    #[inline]
+    fn edge_endpoints(&self, edge: G::EdgeId) -> Option<(G::VertexId, G::VertexId)> {
+        self.graph.edge_endpoints(edge)
+    }
+}
+
+impl<G: GraphProp + Neighbors + ?Sized> Neighbors for AsUndirected<'_, G> {
+    type Neighbors<'a>
+        = UndirectedNeighbors<'a, G>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if the underlying graph panics for `vertex`.
+    #[inline]
+    fn neighbors_directed(&self, vertex: G::VertexId, _: Direction) -> UndirectedNeighbors<'_, G> {
+        UndirectedNeighbors {
+            vertex,
+            outgoing: self.graph.neighbors_directed(vertex, Direction::Outgoing),
+            incoming: G::EdgeType::is_directed()
+                .then(|| self.graph.neighbors_directed(vertex, Direction::Incoming)),
+        }
+    }
+}
+
+impl<G: GraphProp + IncidentEdges + ?Sized> IncidentEdges for AsUndirected<'_, G> {
+    type Edges<'a>
+        = UndirectedEdges<'a, G>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if the underlying graph panics for `vertex`.
+    #[inline]
+    fn edges_directed(&self, vertex: G::VertexId, _: Direction) -> UndirectedEdges<'_, G> {
+        UndirectedEdges {
+            vertex,
+            outgoing: self.graph.edges_directed(vertex, Direction::Outgoing),
+            incoming: G::EdgeType::is_directed()
+                .then(|| self.graph.edges_directed(vertex, Direction::Incoming)),
+        }
+    }
+}
+
+impl<G: Visitable + ?Sized> Visitable for AsUndirected<'_, G> {
+    type Map = G::Map;
+
+    #[inline]
+    fn visit_map(&self) -> G::Map {
+        self.graph.visit_map()
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut G::Map) {
+        self.graph.reset_map(map);
+    }
+}
+
+/// An iterator over the neighbours of a vertex in an [`AsUndirected`] view.
+pub struct UndirectedNeighbors<'a, G: Neighbors + ?Sized + 'a> {
+    vertex: G::VertexId,
+    outgoing: G::Neighbors<'a>,
+    incoming: Option<G::Neighbors<'a>>,
+}
+
+impl<G: Neighbors + ?Sized> Iterator for UndirectedNeighbors<'_, G> {
+    type Item = G::VertexId;
+
+    #[inline]
+    fn next(&mut self) -> Option<G::VertexId> {
+        let vertex = self.vertex;
+
+        self.outgoing.next().or_else(|| {
+            // Self-loops were already yielded as outgoing edges.
+            self.incoming
+                .as_mut()?
+                .find(|&neighbour| neighbour != vertex)
+        })
+    }
+}
+
+/// An iterator over the edges of a vertex in an [`AsUndirected`] view, paired with the vertex at
+/// their other end.
+pub struct UndirectedEdges<'a, G: IncidentEdges + ?Sized + 'a> {
+    vertex: G::VertexId,
+    outgoing: G::Edges<'a>,
+    incoming: Option<G::Edges<'a>>,
+}
+
+impl<G: IncidentEdges + ?Sized> Iterator for UndirectedEdges<'_, G> {
+    type Item = (G::EdgeId, G::VertexId);
+
+    #[inline]
+    fn next(&mut self) -> Option<(G::EdgeId, G::VertexId)> {
+        let vertex = self.vertex;
+
+        self.outgoing.next().or_else(|| {
+            // Self-loops were already yielded as outgoing edges.
+            self.incoming
+                .as_mut()?
+                .find(|&(_, neighbour)| neighbour != vertex)
+        })
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    algo::bipartite::is_bipartite,
+    graph::{stable_graph::StableGraph, unstable_graph::UnstableGraph, Cyclic, Directed, Pseudo},
+};
+
+#[test]
+fn reversed() {
+    let mut graph = UnstableGraph::<char, u8, Directed, Cyclic>::directed();
+    let [a, b, c] = ['a', 'b', 'c'].map(|weight| graph.insert_vertex(weight).unwrap());
+    let ab = graph.insert_edge(a, b, 1).unwrap();
+    graph.insert_edge(a, c, 2).unwrap();
+
+    let reversed = Reversed::new(&graph);
+    assert_eq!(reversed.neighbors(a).count(), 0);
+    assert_eq!(reversed.neighbors(b).collect::<Vec<_>>(), [a]);
+    assert_eq!(
+        reversed.neighbors_directed(a, Direction::Incoming).count(),
+        2
+    );
+    assert_eq!(reversed.edge_endpoints(ab), Some((b, a)));
+    assert_eq!(
+        reversed
+            .edges(c)
+            .map(|(_, source)| source)
+            .collect::<Vec<_>>(),
+        [a]
+    );
+    assert_eq!(reversed.edge_weight(ab), Some(&1));
+    assert_eq!(reversed.edge_count(), 2);
+}
+
+#[test]
+fn filtered() {
+    let mut graph = StableGraph::<char, u8, Undirected, Cyclic, Pseudo>::new_undirected();
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|weight| graph.insert_vertex(weight));
+    graph.insert_edge(a, b, 1).unwrap();
+    let bc = graph.insert_edge(b, c, 2).unwrap();
+    let ca = graph.insert_edge(c, a, 3).unwrap();
+    let cd = graph.insert_edge(c, d, 4).unwrap();
+
+    assert!(is_bipartite(&graph).is_err());
+
+    let filtered = Filtered::new(&graph, |vertex| vertex != d, |edge| graph[edge] != 2);
+    assert_eq!(filtered.vertex_count(), 3);
+    assert_eq!(filtered.edge_count(), 2);
+    assert_eq!(filtered.edge_endpoints(bc), None);
+    assert_eq!(filtered.edge_endpoints(cd), None);
+    assert_eq!(filtered.edge_weight(ca), Some(&3));
+    assert_eq!(filtered.vertex_weight(d), None);
+    assert_eq!(filtered.neighbors(c).collect::<Vec<_>>(), [a]);
+    assert_eq!(filtered.neighbors(d).count(), 0);
+    assert!(is_bipartite(&filtered).is_ok());
+
+    // Views compose, since each one implements the traits it relies on.
+    let reversed = Reversed::new(&filtered);
+    assert_eq!(reversed.edge_endpoints(ca), Some((a, c)));
+}
+
+#[test]
+fn as_undirected() {
+    let mut graph = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let [a, b, c] = [(); 3].map(|()| graph.insert_vertex(()).unwrap());
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(c, b, ()).unwrap();
+    let bb = graph.insert_edge(b, b, ()).unwrap();
+
+    let undirected = AsUndirected::new(&graph);
+    assert!(!undirected.is_directed());
+    assert_eq!(undirected.neighbors(a).collect::<Vec<_>>(), [b]);
+    assert_eq!(undirected.neighbors(b).collect::<Vec<_>>(), [b, a, c]);
+    assert_eq!(
+        undirected
+            .edges_directed(b, Direction::Incoming)
+            .filter(|&(edge, _)| edge == bb)
+            .count(),
+        1
+    );
+
+    // An undirected graph already yields every edge in both directions.
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let [a, b] = [(); 2].map(|()| graph.insert_vertex(()).unwrap());
+    graph.insert_edge(a, b, ()).unwrap();
+    assert_eq!(
+        AsUndirected::new(&graph).neighbors(b).collect::<Vec<_>>(),
+        [a]
+    );
+}
@@ -0,0 +1,414 @@
+mod tests;
+
+use super::{
+    traits::{
+        Counts, Data, EdgeEndpoints, EdgeIds, Graph, GraphBase, GraphProp, IncidentEdges,
+        Neighbors, VertexIds, Visitable,
+    },
+    unstable_graph::{EdgeIndex, VertexIndex},
+    Directed, Direction, EdgeType, GraphError,
+};
+use std::{
+    collections::HashMap,
+    iter::{Copied, Map},
+    marker::PhantomData,
+    ops::{Index, IndexMut, Range},
+    slice,
+};
+
+/// One direction of a [`CsrGraph`]'s adjacency. The entries of the `v`th vertex are at
+/// `offsets[v]..offsets[v + 1]` of `targets`, and `edges` holds the edge that each entry stands
+/// for.
+#[derive(Clone, Debug, Default)]
+struct Rows {
+    offsets: Vec<usize>,
+    targets: Vec<VertexIndex>,
+    edges: Vec<EdgeIndex>,
+}
+
+impl Rows {
+    /// Sorts `entries` of `(vertex, neighbour, edge)` into the rows of `vertex_count` vertices,
+    /// keeping the order of the entries within each row.
+    fn new(vertex_count: usize, entries: &[(usize, VertexIndex, EdgeIndex)]) -> Self {
+        let mut offsets = vec![0; vertex_count + 1];
+        for &(vertex, _, _) in entries {
+            offsets[vertex + 1] += 1;
+        }
+
+        for vertex in 0..vertex_count {
+            offsets[vertex + 1] += offsets[vertex];
+        }
+
+        let mut next = offsets.clone();
+        let mut targets = vec![VertexIndex::default(); entries.len()];
+        let mut edges = vec![EdgeIndex::default(); entries.len()];
+
+        for &(vertex, neighbour, edge) in entries {
+            targets[next[vertex]] = neighbour;
+            edges[next[vertex]] = edge;
+            next[vertex] += 1;
+        }
+
+        Rows {
+            offsets,
+            targets,
+            edges,
+        }
+    }
+
+    #[inline]
+    fn row(&self, vertex: VertexIndex) -> Range<usize> {
+        self.offsets[vertex.index()]..self.offsets[vertex.index() + 1]
+    }
+}
+
+/// An immutable graph in [compressed sparse row](https://en.wikipedia.org/wiki/Sparse_matrix)
+/// form: the neighbours of every vertex are stored next to each other in one flat array, which
+/// makes traversal fast and cache-friendly. A [`CsrGraph`] is built once, either from another
+/// graph with [`from_graph`][`Self::from_graph()`] or from a list of edges with
+/// [`from_edges`][`Self::from_edges()`], and its shape cannot change afterwards; its weights can.
+#[derive(Clone, Debug)]
+pub struct CsrGraph<V, E, D: EdgeType = Directed> {
+    vertices: Vec<V>,
+    edges: Vec<E>,
+    endpoints: Vec<(VertexIndex, VertexIndex)>,
+    /// The outgoing and incoming rows of a directed graph. An undirected graph lists every edge
+    /// at both of its endpoints in the first and leaves the second empty.
+    rows: [Rows; 2],
+    _directed: PhantomData<D>,
+}
+
+impl<V, E, D: EdgeType> CsrGraph<V, E, D> {
+    /// Creates a [`CsrGraph`] with the given vertex weights and `edges` of
+    /// `(source, target, weight)`, where the `i`th edge gets the [`EdgeIndex`] `i`. The edges need
+    /// not be sorted; every vertex lists its edges in the order that they are given, so a list that
+    /// is sorted by source and target gives every vertex its neighbours in order.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if an edge's endpoint is not the index of one of `vertices`.
+    pub fn from_edges<I>(
+        vertices: Vec<V>,
+        edges: I,
+    ) -> Result<Self, GraphError<VertexIndex, EdgeIndex>>
+    where
+        I: IntoIterator<Item = (VertexIndex, VertexIndex, E)>,
+    {
+        let mut endpoints = vec![];
+        let mut weights = vec![];
+
+        for (source, target, weight) in edges {
+            for vertex in [source, target] {
+                if vertex.index() >= vertices.len() {
+                    return Err(GraphError::InvalidVertex(vertex));
+                }
+            }
+
+            endpoints.push((source, target));
+            weights.push(weight);
+        }
+
+        Ok(Self::from_parts(vertices, weights, endpoints))
+    }
+
+    /// Creates a [`CsrGraph`] with the same vertices and edges as `graph`. Vertices are numbered
+    /// in the order of [`vertex_ids`][`VertexIds::vertex_ids()`] and edges in the order of
+    /// [`edge_ids`][`EdgeIds::edge_ids()`]; the returned map gives the [`VertexIndex`] of every
+    /// vertex of `graph`.
+    pub fn from_graph<G>(graph: &G) -> (Self, HashMap<G::VertexId, VertexIndex>)
+    where
+        G: Graph<EdgeType = D, VertexWeight = V, EdgeWeight = E> + ?Sized,
+        V: Clone,
+        E: Clone,
+    {
+        let mut vertices = Vec::with_capacity(graph.vertex_count());
+        let mut mapping = HashMap::with_capacity(graph.vertex_count());
+
+        for id in graph.vertex_ids() {
+            let weight = graph
+                .vertex_weight(id)
+                .expect("Vertex without a weight in graph.");
+
+            mapping.insert(id, VertexIndex::new(vertices.len()));
+            vertices.push(weight.clone());
+        }
+
+        let mut endpoints = Vec::with_capacity(graph.edge_count());
+        let mut weights = Vec::with_capacity(graph.edge_count());
+
+        for id in graph.edge_ids() {
+            let (source, target) = graph
+                .edge_endpoints(id)
+                .expect("Edge without endpoints in graph.");
+            let weight = graph
+                .edge_weight(id)
+                .expect("Edge without a weight in graph.");
+
+            endpoints.push((mapping[&source], mapping[&target]));
+            weights.push(weight.clone());
+        }
+
+        (Self::from_parts(vertices, weights, endpoints), mapping)
+    }
+
+    /// Builds the rows of a [`CsrGraph`] whose `endpoints` are known to be in bounds.
+    fn from_parts(
+        vertices: Vec<V>,
+        edges: Vec<E>,
+        endpoints: Vec<(VertexIndex, VertexIndex)>,
+    ) -> Self {
+        let mut outgoing = Vec::with_capacity(endpoints.len());
+        let mut incoming = Vec::with_capacity(endpoints.len());
+
+        for (index, &(source, target)) in endpoints.iter().enumerate() {
+            let index = EdgeIndex::new(index);
+            outgoing.push((source.index(), target, index));
+
+            if D::is_directed() {
+                incoming.push((target.index(), source, index));
+            } else if source != target {
+                outgoing.push((target.index(), source, index));
+            }
+        }
+
+        let rows = if D::is_directed() {
+            [
+                Rows::new(vertices.len(), &outgoing),
+                Rows::new(vertices.len(), &incoming),
+            ]
+        } else {
+            [Rows::new(vertices.len(), &outgoing), Rows::default()]
+        };
+
+        CsrGraph {
+            vertices,
+            edges,
+            endpoints,
+            rows,
+            _directed: PhantomData,
+        }
+    }
+
+    /// Returns the rows that list the edges of `direction`.
+    #[inline]
+    fn rows(&self, direction: Direction) -> &Rows {
+        match (D::is_directed(), direction) {
+            (true, Direction::Incoming) => &self.rows[1],
+            _ => &self.rows[0],
+        }
+    }
+
+    /// Returns `true` if `index` refers to a vertex in this [`CsrGraph`].
+    #[inline]
+    pub fn contains_vertex(&self, index: VertexIndex) -> bool {
+        index.index() < self.vertices.len()
+    }
+
+    /// Returns the number of edges that enter the vertex at `index`. In an undirected
+    /// [`CsrGraph`], this is the number of edges incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn in_degree(&self, index: VertexIndex) -> usize {
+        self.rows(Direction::Incoming).row(index).len()
+    }
+
+    /// Returns the number of edges that leave the vertex at `index`. In an undirected
+    /// [`CsrGraph`], this is the number of edges incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn out_degree(&self, index: VertexIndex) -> usize {
+        self.rows(Direction::Outgoing).row(index).len()
+    }
+}
+
+/// An iterator over the edges that leave or enter a vertex of a [`CsrGraph`], paired with the
+/// vertex at their other end.
+#[derive(Clone, Debug)]
+pub struct Edges<'a> {
+    edges: slice::Iter<'a, EdgeIndex>,
+    targets: slice::Iter<'a, VertexIndex>,
+}
+
+impl Iterator for Edges<'_> {
+    type Item = (EdgeIndex, VertexIndex);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.edges.next()?, *self.targets.next()?))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Edges<'_> {}
+
+impl<V, E, D: EdgeType> GraphBase for CsrGraph<V, E, D> {
+    type VertexId = VertexIndex;
+    type EdgeId = EdgeIndex;
+}
+
+impl<V, E, D: EdgeType> GraphProp for CsrGraph<V, E, D> {
+    type EdgeType = D;
+}
+
+impl<V, E, D: EdgeType> Data for CsrGraph<V, E, D> {
+    type VertexWeight = V;
+    type EdgeWeight = E;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: VertexIndex) -> Option<&V> {
+        self.vertices.get(vertex.index())
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: EdgeIndex) -> Option<&E> {
+        self.edges.get(edge.index())
+    }
+}
+
+impl<V, E, D: EdgeType> Counts for CsrGraph<V, E, D> {
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl<V, E, D: EdgeType> VertexIds for CsrGraph<V, E, D> {
+    type VertexIds<'a>
+        = Map<Range<usize>, fn(usize) -> VertexIndex>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        (0..self.vertices.len()).map(VertexIndex::new)
+    }
+}
+
+impl<V, E, D: EdgeType> EdgeIds for CsrGraph<V, E, D> {
+    type EdgeIds<'a>
+        = Map<Range<usize>, fn(usize) -> EdgeIndex>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> Self::EdgeIds<'_> {
+        (0..self.edges.len()).map(EdgeIndex::new)
+    }
+}
+
+impl<V, E, D: EdgeType> EdgeEndpoints for CsrGraph<V, E, D> {
+    #[inline]
+    fn edge_endpoints(&self, edge: EdgeIndex) -> Option<(VertexIndex, VertexIndex)> {
+        self.endpoints.get(edge.index()).copied()
+    }
+}
+
+impl<V, E, D: EdgeType> IncidentEdges for CsrGraph<V, E, D> {
+    type Edges<'a>
+        = Edges<'a>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is out of bounds.
+    #[inline]
+    fn edges_directed(&self, vertex: VertexIndex, direction: Direction) -> Edges<'_> {
+        let rows = self.rows(direction);
+        let row = rows.row(vertex);
+
+        Edges {
+            edges: rows.edges[row.clone()].iter(),
+            targets: rows.targets[row].iter(),
+        }
+    }
+}
+
+impl<V, E, D: EdgeType> Neighbors for CsrGraph<V, E, D> {
+    type Neighbors<'a>
+        = Copied<slice::Iter<'a, VertexIndex>>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is out of bounds.
+    #[inline]
+    fn neighbors_directed(&self, vertex: VertexIndex, direction: Direction) -> Self::Neighbors<'_> {
+        let rows = self.rows(direction);
+        rows.targets[rows.row(vertex)].iter().copied()
+    }
+}
+
+impl<V, E, D: EdgeType> Visitable for CsrGraph<V, E, D> {
+    type Map = Vec<bool>;
+
+    #[inline]
+    fn visit_map(&self) -> Vec<bool> {
+        vec![false; self.vertices.len()]
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut Vec<bool>) {
+        map.clear();
+        map.resize(self.vertices.len(), false);
+    }
+}
+
+impl<V, E, D: EdgeType> Index<VertexIndex> for CsrGraph<V, E, D> {
+    type Output = V;
+
+    /// Returns the weight of the vertex at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: VertexIndex) -> &V {
+        &self.vertices[index.index()]
+    }
+}
+
+impl<V, E, D: EdgeType> IndexMut<VertexIndex> for CsrGraph<V, E, D> {
+    /// Returns the weight of the vertex at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: VertexIndex) -> &mut V {
+        &mut self.vertices[index.index()]
+    }
+}
+
+impl<V, E, D: EdgeType> Index<EdgeIndex> for CsrGraph<V, E, D> {
+    type Output = E;
+
+    /// Returns the weight of the edge at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: EdgeIndex) -> &E {
+        &self.edges[index.index()]
+    }
+}
+
+impl<V, E, D: EdgeType> IndexMut<EdgeIndex> for CsrGraph<V, E, D> {
+    /// Returns the weight of the edge at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: EdgeIndex) -> &mut E {
+        &mut self.edges[index.index()]
+    }
+}
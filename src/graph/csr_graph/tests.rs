@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    algo::cores::core_numbers,
+    graph::{stable_graph::StableGraph, unstable_graph::UnstableGraph, Cyclic, Undirected},
+};
+
+#[test]
+fn from_graph() {
+    let mut graph = UnstableGraph::<char, u32, Directed, Cyclic>::directed();
+    let [a, b, c] = ['a', 'b', 'c'].map(|weight| graph.insert_vertex(weight).unwrap());
+    let ab = graph.insert_edge(a, b, 1).unwrap();
+    graph.insert_edge(a, c, 2).unwrap();
+    graph.insert_edge(c, a, 3).unwrap();
+    graph.insert_edge(b, b, 4).unwrap();
+
+    let (csr, mapping) = CsrGraph::from_graph(&graph);
+    let [a, b, c] = [a, b, c].map(|vertex| mapping[&vertex]);
+
+    assert_eq!(csr.vertex_count(), 3);
+    assert_eq!(csr.edge_count(), 4);
+    assert_eq!(csr[b], 'b');
+    assert_eq!(csr[ab], 1);
+    assert_eq!(csr.neighbors(a).collect::<Vec<_>>(), [b, c]);
+    assert_eq!(
+        csr.neighbors_directed(a, Direction::Incoming)
+            .collect::<Vec<_>>(),
+        [c]
+    );
+    assert_eq!(csr.neighbors(b).collect::<Vec<_>>(), [b]);
+    assert_eq!((csr.out_degree(a), csr.in_degree(a)), (2, 1));
+    assert_eq!((csr.out_degree(b), csr.in_degree(b)), (1, 2));
+}
+
+#[test]
+fn undirected() {
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let v = (0..5).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    let (csr, mapping) = CsrGraph::from_graph(&graph);
+    let [a, b, c, d, e] = [0, 1, 2, 3, 4].map(|index| mapping[&v[index]]);
+
+    assert_eq!(csr.neighbors(c).collect::<Vec<_>>(), [b, a, d]);
+    assert_eq!(csr.neighbors(d).collect::<Vec<_>>(), [c, d]);
+    assert_eq!(csr.neighbors(e).count(), 0);
+    assert_eq!((csr.out_degree(d), csr.in_degree(d)), (2, 2));
+
+    let cores = core_numbers(&csr);
+    assert_eq!(cores[&a], 2);
+    assert_eq!(cores[&e], 0);
+}
+
+#[test]
+fn from_edges() {
+    let v = [0, 1, 2].map(VertexIndex::new);
+    let csr = CsrGraph::<(), _, Directed>::from_edges(
+        vec![(); 3],
+        [(v[0], v[1], 'a'), (v[0], v[2], 'b'), (v[1], v[2], 'c')],
+    )
+    .unwrap();
+
+    assert_eq!(
+        csr.edges(v[0]).collect::<Vec<_>>(),
+        [(EdgeIndex::new(0), v[1]), (EdgeIndex::new(1), v[2])]
+    );
+    assert_eq!(csr.edge_endpoints(EdgeIndex::new(2)), Some((v[1], v[2])));
+    assert_eq!(csr.edge_weight(EdgeIndex::new(3)), None);
+
+    let missing = VertexIndex::new(3);
+    assert_eq!(
+        CsrGraph::<(), (), Directed>::from_edges(vec![(); 3], [(v[0], missing, ())]).err(),
+        Some(GraphError::InvalidVertex(missing))
+    );
+}
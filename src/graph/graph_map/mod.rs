@@ -0,0 +1,328 @@
+mod tests;
+
+use super::{
+    stable_graph::{EdgeKey, Edges, StableGraph, VertexKey},
+    traits::{
+        Counts, Data, EdgeEndpoints, EdgeIds, GraphBase, GraphProp, IncidentEdges, Neighbors,
+        VertexIds, Visitable,
+    },
+    Acyclic, Cyclic, Cyclicness, Directed, Direction, EdgeType, GraphError, Undirected,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Index, IndexMut},
+};
+
+/// A graph whose vertices are identified by their weights, so that there is at most one vertex
+/// with a given weight and it can be looked up without keeping a separate map of keys. It is
+/// backed by a [`StableGraph`], and the traits use that graph's [`VertexKey`]s and [`EdgeKey`]s,
+/// which [`key`][`Self::key()`] finds for a weight.
+#[derive(Clone, Debug)]
+pub struct GraphMap<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> {
+    graph: StableGraph<V, E, D, C>,
+    keys: HashMap<V, VertexKey>,
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> GraphMap<V, E, D, C> {
+    #[inline]
+    fn from_graph(graph: StableGraph<V, E, D, C>) -> Self {
+        GraphMap {
+            graph,
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Inserts an edge from the vertex `source` to the vertex `destination`, first inserting
+    /// either of them that is not in this [`GraphMap`] yet.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if this [`GraphMap`] is acyclic and the edge would introduce a cycle. Any
+    /// vertex that was inserted for the edge stays in the graph.
+    pub fn add_edge(
+        &mut self,
+        source: V,
+        destination: V,
+        weight: E,
+    ) -> Result<EdgeKey, GraphError<VertexKey, EdgeKey>> {
+        let source = self.insert_vertex(source);
+        let destination = self.insert_vertex(destination);
+
+        self.graph.insert_edge(source, destination, weight)
+    }
+
+    /// Returns `true` if there is an edge from the vertex `source` to the vertex `target`, or
+    /// between them if this [`GraphMap`] is undirected.
+    pub fn contains_edge(&self, source: &V, target: &V) -> bool {
+        match (self.key(source), self.key(target)) {
+            (Some(source), Some(target)) => self
+                .graph
+                .neighbors(source)
+                .any(|neighbour| neighbour == target),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `weight` is a vertex of this [`GraphMap`].
+    #[inline]
+    pub fn contains_vertex(&self, weight: &V) -> bool {
+        self.keys.contains_key(weight)
+    }
+
+    /// Returns the number of edges in this [`GraphMap`].
+    #[inline]
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Returns the edges that leave or enter the vertex `weight` in `direction`, paired with the
+    /// vertex at their other end, or nothing if `weight` is not in this [`GraphMap`].
+    pub fn edges_of<'a>(
+        &'a self,
+        weight: &V,
+        direction: Direction,
+    ) -> impl Iterator<Item = (EdgeKey, &'a V)> + 'a {
+        self.key(weight)
+            .into_iter()
+            .flat_map(move |key| self.graph.edges_directed(key, direction))
+            .map(|(edge, neighbour)| (edge, &self.graph[neighbour]))
+    }
+
+    /// Inserts the vertex `weight` into this [`GraphMap`] and returns its key, or returns the key
+    /// that it already has.
+    pub fn insert_vertex(&mut self, weight: V) -> VertexKey {
+        if let Some(&key) = self.keys.get(&weight) {
+            return key;
+        }
+
+        let key = self.graph.insert_vertex(weight.clone());
+        self.keys.insert(weight, key);
+
+        key
+    }
+
+    /// Returns the key of the vertex `weight`, or `None` if it is not in this [`GraphMap`].
+    #[inline]
+    pub fn key(&self, weight: &V) -> Option<VertexKey> {
+        self.keys.get(weight).copied()
+    }
+
+    /// Removes the edge at `key` and returns its weight, or returns `None` if there is no such
+    /// edge.
+    #[inline]
+    pub fn remove_edge(&mut self, key: EdgeKey) -> Option<E> {
+        self.graph.remove_edge(key)
+    }
+
+    /// Removes the vertex `weight` along with every edge incident to it and returns it, or
+    /// returns `None` if it is not in this [`GraphMap`].
+    pub fn remove_vertex(&mut self, weight: &V) -> Option<V> {
+        let key = self.keys.remove(weight)?;
+        self.graph.remove_vertex(key)
+    }
+
+    /// Returns the number of vertices in this [`GraphMap`].
+    #[inline]
+    pub fn vertex_count(&self) -> usize {
+        self.graph.vertex_count()
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone> GraphMap<V, E, Directed, Cyclic> {
+    /// Creates a new directed cyclic [`GraphMap`].
+    #[inline]
+    pub fn directed() -> Self {
+        Self::from_graph(StableGraph::<V, E, Directed, Cyclic>::new_directed())
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone> GraphMap<V, E, Directed, Acyclic> {
+    /// Creates a new directed acyclic [`GraphMap`].
+    #[inline]
+    pub fn directed() -> Self {
+        Self::from_graph(StableGraph::<V, E, Directed, Acyclic>::new_directed())
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone> GraphMap<V, E, Undirected, Cyclic> {
+    /// Creates a new undirected cyclic [`GraphMap`].
+    #[inline]
+    pub fn undirected() -> Self {
+        Self::from_graph(StableGraph::<V, E, Undirected, Cyclic>::new_undirected())
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone> GraphMap<V, E, Undirected, Acyclic> {
+    /// Creates a new undirected acyclic [`GraphMap`], which is a forest.
+    #[inline]
+    pub fn undirected() -> Self {
+        Self::from_graph(StableGraph::<V, E, Undirected, Acyclic>::new_undirected())
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> GraphBase
+    for GraphMap<V, E, D, C>
+{
+    type VertexId = VertexKey;
+    type EdgeId = EdgeKey;
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> GraphProp
+    for GraphMap<V, E, D, C>
+{
+    type EdgeType = D;
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> Data for GraphMap<V, E, D, C> {
+    type VertexWeight = V;
+    type EdgeWeight = E;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: VertexKey) -> Option<&V> {
+        self.graph.vertex_weight(vertex)
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: EdgeKey) -> Option<&E> {
+        self.graph.edge_weight(edge)
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> Counts for GraphMap<V, E, D, C> {
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.graph.vertex_count()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> VertexIds
+    for GraphMap<V, E, D, C>
+{
+    type VertexIds<'a>
+        = <StableGraph<V, E, D, C> as VertexIds>::VertexIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        self.graph.vertex_ids()
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> EdgeIds for GraphMap<V, E, D, C> {
+    type EdgeIds<'a>
+        = <StableGraph<V, E, D, C> as EdgeIds>::EdgeIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> Self::EdgeIds<'_> {
+        self.graph.edge_ids()
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> EdgeEndpoints
+    for GraphMap<V, E, D, C>
+{
+    #[inline]
+    fn edge_endpoints(&self, edge: EdgeKey) -> Option<(VertexKey, VertexKey)> {
+        self.graph.edge_endpoints(edge)
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> IncidentEdges
+    for GraphMap<V, E, D, C>
+{
+    type Edges<'a>
+        = Edges<'a, E>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` does not refer to a vertex in this [`GraphMap`].
+    #[inline]
+    fn edges_directed(&self, vertex: VertexKey, direction: Direction) -> Edges<'_, E> {
+        self.graph.edges_directed(vertex, direction)
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> Neighbors
+    for GraphMap<V, E, D, C>
+{
+    type Neighbors<'a>
+        = <StableGraph<V, E, D, C> as Neighbors>::Neighbors<'a>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` does not refer to a vertex in this [`GraphMap`].
+    #[inline]
+    fn neighbors_directed(&self, vertex: VertexKey, direction: Direction) -> Self::Neighbors<'_> {
+        self.graph.neighbors_directed(vertex, direction)
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> Visitable
+    for GraphMap<V, E, D, C>
+{
+    type Map = <StableGraph<V, E, D, C> as Visitable>::Map;
+
+    #[inline]
+    fn visit_map(&self) -> Self::Map {
+        self.graph.visit_map()
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut Self::Map) {
+        self.graph.reset_map(map);
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> Index<VertexKey>
+    for GraphMap<V, E, D, C>
+{
+    type Output = V;
+
+    /// Returns the weight of the vertex at `key`. There is no mutable counterpart, as a vertex's
+    /// weight is what identifies it.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to a vertex in this [`GraphMap`].
+    #[inline]
+    fn index(&self, key: VertexKey) -> &V {
+        &self.graph[key]
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> Index<EdgeKey>
+    for GraphMap<V, E, D, C>
+{
+    type Output = E;
+
+    /// Returns the weight of the edge at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to an edge in this [`GraphMap`].
+    #[inline]
+    fn index(&self, key: EdgeKey) -> &E {
+        &self.graph[key]
+    }
+}
+
+impl<V: Clone + Eq + Hash, E: Clone, D: EdgeType, C: Cyclicness> IndexMut<EdgeKey>
+    for GraphMap<V, E, D, C>
+{
+    /// Returns the weight of the edge at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to an edge in this [`GraphMap`].
+    #[inline]
+    fn index_mut(&mut self, key: EdgeKey) -> &mut E {
+        &mut self.graph[key]
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use super::*;
+use crate::algo::colouring::dsatur;
+
+#[test]
+fn modules() {
+    let mut graph = GraphMap::<&str, u32, Directed, Acyclic>::directed();
+    let core_io = graph.add_edge("core", "io", 1).unwrap();
+    graph.add_edge("core", "fmt", 2).unwrap();
+    graph.add_edge("fmt", "io", 3).unwrap();
+
+    assert_eq!(graph.vertex_count(), 3);
+    assert_eq!(graph.insert_vertex("core"), graph.key(&"core").unwrap());
+    assert_eq!(graph.vertex_count(), 3);
+    assert!(graph.contains_edge(&"core", &"io"));
+    assert!(!graph.contains_edge(&"io", &"core"));
+    assert_eq!(graph[core_io], 1);
+
+    let mut dependencies = graph
+        .edges_of(&"core", Direction::Outgoing)
+        .map(|(edge, &module)| (module, graph[edge]))
+        .collect::<Vec<_>>();
+    dependencies.sort();
+    assert_eq!(dependencies, [("fmt", 2), ("io", 1)]);
+    assert_eq!(graph.edges_of(&"alloc", Direction::Outgoing).count(), 0);
+
+    let (io, core) = (graph.key(&"io").unwrap(), graph.key(&"core").unwrap());
+    assert_eq!(
+        graph.add_edge("io", "core", 4),
+        Err(GraphError::WouldCycle(vec![core, io]))
+    );
+
+    assert_eq!(graph.remove_vertex(&"fmt"), Some("fmt"));
+    assert_eq!(graph.remove_vertex(&"fmt"), None);
+    assert!(!graph.contains_vertex(&"fmt"));
+    assert_eq!(graph.edge_count(), 1);
+}
+
+#[test]
+fn undirected() {
+    let mut graph = GraphMap::<String, (), Undirected, Cyclic>::undirected();
+    for (a, b) in [("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")] {
+        graph.add_edge(a.to_owned(), b.to_owned(), ()).unwrap();
+    }
+
+    assert!(graph.contains_edge(&"b".to_owned(), &"a".to_owned()));
+    assert_eq!(
+        graph.edges_of(&"c".to_owned(), Direction::Incoming).count(),
+        3
+    );
+
+    let colours = dsatur(&graph);
+    assert_eq!(colours.values().max(), Some(&2));
+}
+
+#[test]
+fn forest() {
+    let mut graph = GraphMap::<&str, (), Undirected, Acyclic>::undirected();
+    graph.add_edge("a", "b", ()).unwrap();
+    graph.add_edge("c", "b", ()).unwrap();
+
+    let [a, b, c] = ["a", "b", "c"].map(|weight| graph.key(&weight).unwrap());
+    assert_eq!(
+        graph.add_edge("a", "c", ()),
+        Err(GraphError::WouldCycle(vec![c, b, a]))
+    );
+    assert!(matches!(
+        graph.add_edge("d", "d", ()),
+        Err(GraphError::WouldCycle(_))
+    ));
+
+    // The self-loop was rejected, but the vertex that was inserted for it stays.
+    assert!(graph.contains_vertex(&"d"));
+    graph.add_edge("d", "a", ()).unwrap();
+    assert_eq!(graph.edge_count(), 3);
+}
@@ -0,0 +1,569 @@
+mod tests;
+
+use super::{
+    traits::{
+        Counts, Data, EdgeEndpoints, EdgeIds, GraphBase, GraphProp, IncidentEdges, Neighbors,
+        VertexIds, Visitable,
+    },
+    unstable_graph::{Edge, EdgeIndex, IndexType, UnstableGraph, VertexIndex},
+    would_cycle, Acyclic, Components, Cyclic, Cyclicness, Directed, Direction, EdgePolicy,
+    EdgeType, GraphError, Undirected,
+};
+use std::{
+    iter::{self, Enumerate, Map},
+    marker::PhantomData,
+    ops::{Index, IndexMut, Range},
+    slice,
+};
+
+/// The handle of an edge in a [`MatrixGraph`]: its source and target. The endpoints of an
+/// undirected edge are in ascending order.
+pub type MatrixEdge = (VertexIndex, VertexIndex);
+
+/// A graph that stores its edges in an adjacency matrix, which answers
+/// [`contains_edge`][`Self::contains_edge()`] in constant time at the cost of memory quadratic in
+/// the number of vertices. It suits small, dense graphs. There is at most one edge from one vertex
+/// to another, or between two vertices if the graph is undirected.
+#[derive(Clone, Debug)]
+pub struct MatrixGraph<V, E, D: EdgeType, C: Cyclicness> {
+    vertices: Vec<V>,
+    /// The weight of the edge from the `a`th to the `b`th vertex is at `a * stride + b`. An
+    /// undirected edge is only stored with `a <= b`.
+    matrix: Vec<Option<E>>,
+    stride: usize,
+    edge_count: usize,
+    _directed: PhantomData<D>,
+    _cyclic: PhantomData<C>,
+    components: Components<VertexIndex>,
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> MatrixGraph<V, E, D, C> {
+    fn with_capacity(cap: usize) -> Self {
+        MatrixGraph {
+            vertices: Vec::with_capacity(cap),
+            matrix: iter::repeat_with(|| None).take(cap * cap).collect(),
+            stride: cap,
+            edge_count: 0,
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            components: None,
+        }
+    }
+
+    /// Returns the handle of the edge from `source` to `target`.
+    #[inline]
+    fn handle(source: VertexIndex, target: VertexIndex) -> MatrixEdge {
+        if D::is_directed() || source <= target {
+            (source, target)
+        } else {
+            (target, source)
+        }
+    }
+
+    /// Returns the position in `matrix` of the edge from `source` to `target`.
+    #[inline]
+    fn slot(&self, source: VertexIndex, target: VertexIndex) -> usize {
+        let (source, target) = Self::handle(source, target);
+        source.index() * self.stride + target.index()
+    }
+
+    /// Returns `true` if there is an edge from `source` to `target`, or between them if this
+    /// [`MatrixGraph`] is undirected.
+    #[inline]
+    pub fn contains_edge(&self, source: VertexIndex, target: VertexIndex) -> bool {
+        self.contains_vertex(source)
+            && self.contains_vertex(target)
+            && self.matrix[self.slot(source, target)].is_some()
+    }
+
+    /// Returns `true` if `index` refers to a vertex in this [`MatrixGraph`].
+    #[inline]
+    pub fn contains_vertex(&self, index: VertexIndex) -> bool {
+        index.index() < self.vertices.len()
+    }
+
+    /// Returns the number of edges in this [`MatrixGraph`].
+    #[inline]
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Inserts an edge from `source` to `destination` into this [`MatrixGraph`].
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `source` or `destination` is out of bounds, if they are already
+    /// connected, or if this [`MatrixGraph`] is acyclic and the edge would introduce a cycle.
+    pub fn insert_edge(
+        &mut self,
+        source: VertexIndex,
+        destination: VertexIndex,
+        weight: E,
+    ) -> Result<MatrixEdge, GraphError<VertexIndex, MatrixEdge>> {
+        for vertex in [source, destination] {
+            if !self.contains_vertex(vertex) {
+                return Err(GraphError::InvalidVertex(vertex));
+            }
+        }
+
+        if self.contains_edge(source, destination) {
+            return Err(GraphError::ParallelEdge(source, destination));
+        }
+
+        if !C::is_cyclic() {
+            let mut components = self.components.take();
+            let cycle = would_cycle(self, &mut components, source, destination);
+            self.components = components;
+
+            if let Some(cycle) = cycle {
+                return Err(GraphError::WouldCycle(cycle));
+            }
+        }
+
+        Ok(self.insert_edge_unchecked(source, destination, weight))
+    }
+
+    /// Inserts an edge without checking it; its endpoints must be in this [`MatrixGraph`] and not
+    /// yet connected.
+    fn insert_edge_unchecked(
+        &mut self,
+        source: VertexIndex,
+        destination: VertexIndex,
+        weight: E,
+    ) -> MatrixEdge {
+        if let Some(components) = &mut self.components {
+            components.union(source, destination);
+        }
+
+        let slot = self.slot(source, destination);
+        self.matrix[slot] = Some(weight);
+        self.edge_count += 1;
+
+        Self::handle(source, destination)
+    }
+
+    /// Inserts a vertex into this [`MatrixGraph`], growing its matrix if it is full.
+    pub fn insert_vertex(&mut self, weight: V) -> VertexIndex {
+        if self.vertices.len() == self.stride {
+            let stride = (self.stride * 2).max(4);
+            let mut matrix = iter::repeat_with(|| None)
+                .take(stride * stride)
+                .collect::<Vec<_>>();
+
+            for (slot, edge) in self.matrix.iter_mut().enumerate() {
+                matrix[slot / self.stride * stride + slot % self.stride] = edge.take();
+            }
+
+            self.matrix = matrix;
+            self.stride = stride;
+        }
+
+        self.vertices.push(weight);
+        VertexIndex::new(self.vertices.len() - 1)
+    }
+
+    /// Removes the edge from `source` to `target`, or between them if this [`MatrixGraph`] is
+    /// undirected, and returns its weight, or returns `None` if there is no such edge.
+    pub fn remove_edge(&mut self, source: VertexIndex, target: VertexIndex) -> Option<E> {
+        if !self.contains_vertex(source) || !self.contains_vertex(target) {
+            return None;
+        }
+
+        let slot = self.slot(source, target);
+        let weight = self.matrix[slot].take()?;
+        self.edge_count -= 1;
+        self.components = None;
+
+        Some(weight)
+    }
+
+    /// Returns the number of edges that enter the vertex at `index`. In an undirected
+    /// [`MatrixGraph`], this is the number of edges incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn in_degree(&self, index: VertexIndex) -> usize {
+        self.edges_directed(index, Direction::Incoming).count()
+    }
+
+    /// Returns the number of edges that leave the vertex at `index`. In an undirected
+    /// [`MatrixGraph`], this is the number of edges incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn out_degree(&self, index: VertexIndex) -> usize {
+        self.edges_directed(index, Direction::Outgoing).count()
+    }
+
+    /// Returns the number of vertices in this [`MatrixGraph`].
+    #[inline]
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+}
+
+impl<V, E> MatrixGraph<V, E, Directed, Cyclic> {
+    /// Creates a new directed cyclic [`MatrixGraph`].
+    #[inline]
+    pub fn directed() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new directed cyclic [`MatrixGraph`] with a preallocated matrix for `cap`
+    /// vertices.
+    #[inline]
+    pub fn directed_with_capacity(cap: usize) -> Self {
+        Self::with_capacity(cap)
+    }
+}
+
+impl<V, E> MatrixGraph<V, E, Directed, Acyclic> {
+    /// Creates a new directed acyclic [`MatrixGraph`].
+    #[inline]
+    pub fn directed() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new directed acyclic [`MatrixGraph`] with a preallocated matrix for `cap`
+    /// vertices.
+    #[inline]
+    pub fn directed_with_capacity(cap: usize) -> Self {
+        Self::with_capacity(cap)
+    }
+}
+
+impl<V, E> MatrixGraph<V, E, Undirected, Cyclic> {
+    /// Creates a new undirected cyclic [`MatrixGraph`].
+    #[inline]
+    pub fn undirected() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new undirected cyclic [`MatrixGraph`] with a preallocated matrix for `cap`
+    /// vertices.
+    #[inline]
+    pub fn undirected_with_capacity(cap: usize) -> Self {
+        Self::with_capacity(cap)
+    }
+}
+
+impl<V, E> MatrixGraph<V, E, Undirected, Acyclic> {
+    /// Creates a new undirected acyclic [`MatrixGraph`], which is a forest.
+    #[inline]
+    pub fn undirected() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new undirected acyclic [`MatrixGraph`] with a preallocated matrix for `cap`
+    /// vertices.
+    #[inline]
+    pub fn undirected_with_capacity(cap: usize) -> Self {
+        Self::with_capacity(cap)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType>
+    TryFrom<&UnstableGraph<V, E, D, C, P, Ix>> for MatrixGraph<V, E, D, C>
+{
+    type Error = GraphError<VertexIndex<Ix>, EdgeIndex<Ix>>;
+
+    /// Creates a [`MatrixGraph`] with the same vertices at the same indices as `graph`.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `graph` has parallel edges, which a [`MatrixGraph`] cannot hold.
+    fn try_from(graph: &UnstableGraph<V, E, D, C, P, Ix>) -> Result<Self, Self::Error> {
+        let mut matrix = Self::with_capacity(graph.vertex_count());
+
+        for vertex in &graph.vertices {
+            matrix.insert_vertex(vertex.weight.clone());
+        }
+
+        for edge in &graph.edges {
+            let (source, target) = edge.vertex_indices();
+            let [from, to] = [source, target].map(|vertex| VertexIndex::new(vertex.index()));
+
+            if matrix.contains_edge(from, to) {
+                return Err(GraphError::ParallelEdge(source, target));
+            }
+
+            // `graph` already has the cyclicness of `matrix`.
+            matrix.insert_edge_unchecked(from, to, edge.weight.clone());
+        }
+
+        Ok(matrix)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType>
+    TryFrom<&MatrixGraph<V, E, D, C>> for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type Error = GraphError<VertexIndex, MatrixEdge>;
+
+    /// Creates an [`UnstableGraph`] with the same vertices at the same indices as `graph`. Its
+    /// edges are ordered by source and then by target.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `graph` has a self-loop that `P` does not allow, or if `Ix` cannot
+    /// index all of its vertices and edges.
+    fn try_from(graph: &MatrixGraph<V, E, D, C>) -> Result<Self, Self::Error> {
+        let mut unstable = UnstableGraph::with_capacities(graph.vertex_count(), graph.edge_count());
+
+        for vertex in &graph.vertices {
+            unstable
+                .insert_vertex(vertex.clone())
+                .map_err(|_| GraphError::GraphFull)?;
+        }
+
+        for (source, target) in graph.edge_ids() {
+            if !P::allows_self_loops() && source == target {
+                return Err(GraphError::SelfLoop(source));
+            }
+
+            if unstable.edge_count() > Ix::MAX {
+                return Err(GraphError::GraphFull);
+            }
+
+            let weight = graph[(source, target)].clone();
+            let [source, target] = [source, target].map(|vertex| VertexIndex::new(vertex.index()));
+            unstable.insert_edge_unchecked(Edge::new(source, target, weight));
+        }
+
+        Ok(unstable)
+    }
+}
+
+/// An iterator over the handles of the edges of a [`MatrixGraph`].
+#[derive(Clone, Debug)]
+pub struct EdgeHandles<'a, E> {
+    slots: Enumerate<slice::Iter<'a, Option<E>>>,
+    stride: usize,
+}
+
+impl<E> Iterator for EdgeHandles<'_, E> {
+    type Item = MatrixEdge;
+
+    fn next(&mut self) -> Option<MatrixEdge> {
+        self.slots
+            .by_ref()
+            .find(|(_, edge)| edge.is_some())
+            .map(|(slot, _)| {
+                (
+                    VertexIndex::new(slot / self.stride),
+                    VertexIndex::new(slot % self.stride),
+                )
+            })
+    }
+}
+
+/// An iterator over the edges that leave or enter a vertex of a [`MatrixGraph`], paired with the
+/// vertex at their other end.
+#[derive(Clone, Debug)]
+pub struct Edges<'a, V, E, D: EdgeType, C: Cyclicness> {
+    graph: &'a MatrixGraph<V, E, D, C>,
+    vertex: VertexIndex,
+    direction: Direction,
+    neighbours: Range<usize>,
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> Iterator for Edges<'_, V, E, D, C> {
+    type Item = (MatrixEdge, VertexIndex);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for neighbour in self.neighbours.by_ref() {
+            let neighbour = VertexIndex::new(neighbour);
+            let (source, target) = match self.direction {
+                Direction::Outgoing => (self.vertex, neighbour),
+                Direction::Incoming => (neighbour, self.vertex),
+            };
+
+            if self.graph.matrix[self.graph.slot(source, target)].is_some() {
+                return Some((MatrixGraph::<V, E, D, C>::handle(source, target), neighbour));
+            }
+        }
+
+        None
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> GraphBase for MatrixGraph<V, E, D, C> {
+    type VertexId = VertexIndex;
+    type EdgeId = MatrixEdge;
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> GraphProp for MatrixGraph<V, E, D, C> {
+    type EdgeType = D;
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> Data for MatrixGraph<V, E, D, C> {
+    type VertexWeight = V;
+    type EdgeWeight = E;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: VertexIndex) -> Option<&V> {
+        self.vertices.get(vertex.index())
+    }
+
+    #[inline]
+    fn edge_weight(&self, (source, target): MatrixEdge) -> Option<&E> {
+        if !self.contains_vertex(source) || !self.contains_vertex(target) {
+            return None;
+        }
+
+        self.matrix[self.slot(source, target)].as_ref()
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> Counts for MatrixGraph<V, E, D, C> {
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> VertexIds for MatrixGraph<V, E, D, C> {
+    type VertexIds<'a>
+        = Map<Range<usize>, fn(usize) -> VertexIndex>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        (0..self.vertices.len()).map(VertexIndex::new)
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> EdgeIds for MatrixGraph<V, E, D, C> {
+    type EdgeIds<'a>
+        = EdgeHandles<'a, E>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> EdgeHandles<'_, E> {
+        EdgeHandles {
+            slots: self.matrix.iter().enumerate(),
+            stride: self.stride,
+        }
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> EdgeEndpoints for MatrixGraph<V, E, D, C> {
+    #[inline]
+    fn edge_endpoints(&self, edge: MatrixEdge) -> Option<(VertexIndex, VertexIndex)> {
+        self.edge_weight(edge).map(|_| Self::handle(edge.0, edge.1))
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> IncidentEdges for MatrixGraph<V, E, D, C> {
+    type Edges<'a>
+        = Edges<'a, V, E, D, C>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is out of bounds.
+    #[inline]
+    fn edges_directed(&self, vertex: VertexIndex, direction: Direction) -> Self::Edges<'_> {
+        assert!(
+            self.contains_vertex(vertex),
+            "Vertex index out of bounds for graph."
+        );
+
+        Edges {
+            graph: self,
+            vertex,
+            direction,
+            neighbours: 0..self.vertices.len(),
+        }
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> Neighbors for MatrixGraph<V, E, D, C> {
+    type Neighbors<'a>
+        = Map<Edges<'a, V, E, D, C>, fn((MatrixEdge, VertexIndex)) -> VertexIndex>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is out of bounds.
+    #[inline]
+    fn neighbors_directed(&self, vertex: VertexIndex, direction: Direction) -> Self::Neighbors<'_> {
+        self.edges_directed(vertex, direction)
+            .map(|(_, neighbour)| neighbour)
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> Visitable for MatrixGraph<V, E, D, C> {
+    type Map = Vec<bool>;
+
+    #[inline]
+    fn visit_map(&self) -> Vec<bool> {
+        vec![false; self.vertices.len()]
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut Vec<bool>) {
+        map.clear();
+        map.resize(self.vertices.len(), false);
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> Index<VertexIndex> for MatrixGraph<V, E, D, C> {
+    type Output = V;
+
+    /// Returns the weight of the vertex at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: VertexIndex) -> &V {
+        &self.vertices[index.index()]
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> IndexMut<VertexIndex> for MatrixGraph<V, E, D, C> {
+    /// Returns the weight of the vertex at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: VertexIndex) -> &mut V {
+        &mut self.vertices[index.index()]
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> Index<MatrixEdge> for MatrixGraph<V, E, D, C> {
+    type Output = E;
+
+    /// Returns the weight of the edge `(source, target)`.
+    ///
+    /// # Panics:
+    /// Panics if there is no such edge.
+    #[inline]
+    fn index(&self, edge: MatrixEdge) -> &E {
+        self.edge_weight(edge).expect("No such edge in graph.")
+    }
+}
+
+impl<V, E, D: EdgeType, C: Cyclicness> IndexMut<MatrixEdge> for MatrixGraph<V, E, D, C> {
+    /// Returns the weight of the edge `(source, target)`.
+    ///
+    /// # Panics:
+    /// Panics if there is no such edge.
+    #[inline]
+    fn index_mut(&mut self, (source, target): MatrixEdge) -> &mut E {
+        assert!(
+            self.contains_vertex(source) && self.contains_vertex(target),
+            "No such edge in graph."
+        );
+
+        let slot = self.slot(source, target);
+        self.matrix[slot].as_mut().expect("No such edge in graph.")
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{algo::clique::maximum_clique, graph::Simple};
+
+#[test]
+fn directed() {
+    let mut graph = MatrixGraph::<char, u32, Directed, Cyclic>::directed();
+    let v = ['a', 'b', 'c', 'd', 'e', 'f'].map(|weight| graph.insert_vertex(weight));
+
+    let ab = graph.insert_edge(v[0], v[1], 1).unwrap();
+    graph.insert_edge(v[1], v[0], 2).unwrap();
+    graph.insert_edge(v[5], v[5], 3).unwrap();
+    graph.insert_edge(v[4], v[0], 4).unwrap();
+
+    assert_eq!(
+        graph.insert_edge(v[0], v[1], 5),
+        Err(GraphError::ParallelEdge(v[0], v[1]))
+    );
+    assert!(graph.contains_edge(v[1], v[0]));
+    assert!(!graph.contains_edge(v[0], v[4]));
+    assert_eq!(graph[ab], 1);
+    assert_eq!(graph[v[5]], 'f');
+    assert_eq!(graph.edge_count(), 4);
+
+    assert_eq!(graph.neighbors(v[0]).collect::<Vec<_>>(), [v[1]]);
+    assert_eq!(
+        graph
+            .neighbors_directed(v[0], Direction::Incoming)
+            .collect::<Vec<_>>(),
+        [v[1], v[4]]
+    );
+    assert_eq!((graph.out_degree(v[5]), graph.in_degree(v[5])), (1, 1));
+
+    assert_eq!(graph.remove_edge(v[0], v[1]), Some(1));
+    assert_eq!(graph.remove_edge(v[0], v[1]), None);
+    assert_eq!(graph.edge_ids().count(), 3);
+}
+
+#[test]
+fn undirected() {
+    let mut graph = MatrixGraph::<(), (), Undirected, Cyclic>::undirected_with_capacity(2);
+    let v = (0..5).map(|_| graph.insert_vertex(())).collect::<Vec<_>>();
+
+    for (a, b) in [(1, 0), (0, 2), (2, 1), (1, 3), (3, 3)] {
+        graph.insert_edge(v[a], v[b], ()).unwrap();
+    }
+
+    assert_eq!(
+        graph.insert_edge(v[0], v[1], ()),
+        Err(GraphError::ParallelEdge(v[0], v[1]))
+    );
+    assert!(graph.contains_edge(v[0], v[1]) && graph.contains_edge(v[1], v[0]));
+    assert_eq!(graph.edge_endpoints((v[2], v[0])), Some((v[0], v[2])));
+    assert_eq!(
+        graph.edges(v[3]).collect::<Vec<_>>(),
+        [((v[1], v[3]), v[1]), ((v[3], v[3]), v[3])]
+    );
+    assert_eq!(graph.in_degree(v[1]), 3);
+
+    // Growing the matrix keeps every edge between the same vertices.
+    let edges = graph.edge_ids().collect::<Vec<_>>();
+    for _ in 0..4 {
+        graph.insert_vertex(());
+    }
+    assert_eq!(graph.edge_ids().collect::<Vec<_>>(), edges);
+
+    let mut clique = maximum_clique(&graph);
+    clique.sort();
+    assert_eq!(clique, [v[0], v[1], v[2]]);
+}
+
+#[test]
+fn acyclic() {
+    let mut graph = MatrixGraph::<(), (), Directed, Acyclic>::directed();
+    let [a, b, c] = [(); 3].map(|_| graph.insert_vertex(()));
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(b, c, ()).unwrap();
+
+    assert_eq!(
+        graph.insert_edge(c, a, ()),
+        Err(GraphError::WouldCycle(vec![a, b, c]))
+    );
+    assert_eq!(
+        graph.insert_edge(a, a, ()),
+        Err(GraphError::WouldCycle(vec![a]))
+    );
+    assert!(graph.insert_edge(a, c, ()).is_ok());
+}
+
+#[test]
+fn conversions() {
+    let mut unstable = UnstableGraph::<u8, u8, Directed, Cyclic>::directed();
+    let [a, b, c] = [0, 1, 2].map(|weight| unstable.insert_vertex(weight).unwrap());
+    unstable.insert_edge(b, c, 12).unwrap();
+    unstable.insert_edge(a, b, 1).unwrap();
+    unstable.insert_edge(c, c, 22).unwrap();
+
+    let matrix = MatrixGraph::try_from(&unstable).unwrap();
+    assert_eq!(matrix.vertex_count(), 3);
+    assert_eq!(matrix[(b, c)], 12);
+    assert_eq!(matrix[c], 2);
+
+    let unstable = UnstableGraph::<u8, u8, Directed, Cyclic>::try_from(&matrix).unwrap();
+    assert_eq!(unstable.vertex_count(), 3);
+    assert_eq!(
+        unstable
+            .edges
+            .iter()
+            .map(|edge| (edge.vertex_indices(), edge.weight))
+            .collect::<Vec<_>>(),
+        [((a, b), 1), ((b, c), 12), ((c, c), 22)]
+    );
+
+    let mut parallel = unstable;
+    parallel.insert_edge(a, b, 2).unwrap();
+    assert_eq!(
+        MatrixGraph::try_from(&parallel).err(),
+        Some(GraphError::ParallelEdge(a, b))
+    );
+}
+
+#[test]
+fn forest() {
+    let mut graph = MatrixGraph::<(), (), Undirected, Acyclic>::undirected();
+    let [a, b, c] = [(); 3].map(|_| graph.insert_vertex(()));
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(c, b, ()).unwrap();
+
+    assert_eq!(
+        graph.insert_edge(a, c, ()),
+        Err(GraphError::WouldCycle(vec![c, b, a]))
+    );
+    assert_eq!(
+        graph.insert_edge(b, b, ()),
+        Err(GraphError::WouldCycle(vec![b]))
+    );
+
+    // Removing an edge splits its tree, so its ends can be joined another way.
+    graph.remove_edge(b, a);
+    graph.insert_edge(a, c, ()).unwrap();
+    assert_eq!(graph.edge_count(), 2);
+}
+
+#[test]
+fn conversion_typestates() {
+    let mut matrix = MatrixGraph::<(), (), Directed, Cyclic>::directed();
+    let vertices = (0..300)
+        .map(|_| matrix.insert_vertex(()))
+        .collect::<Vec<_>>();
+    matrix.insert_edge(vertices[0], vertices[1], ()).unwrap();
+
+    let narrow = UnstableGraph::<(), (), Directed, Cyclic, Simple, u16>::try_from(&matrix).unwrap();
+    assert_eq!(narrow.edge_count(), 1);
+    assert_eq!(
+        MatrixGraph::try_from(&narrow)
+            .unwrap()
+            .edge_ids()
+            .collect::<Vec<_>>(),
+        [(vertices[0], vertices[1])]
+    );
+
+    matrix.insert_edge(vertices[2], vertices[2], ()).unwrap();
+    assert_eq!(
+        UnstableGraph::<(), (), Directed, Cyclic, Simple>::try_from(&matrix).err(),
+        Some(GraphError::SelfLoop(vertices[2]))
+    );
+
+    // A matrix for more vertices than `u16` can index would take gigabytes, and edgeless vertices
+    // do not need one, so they are pushed directly.
+    let mut large = MatrixGraph::<(), (), Directed, Cyclic>::directed();
+    for _ in 0..=u16::MAX as usize + 1 {
+        large.vertices.push(());
+    }
+    assert_eq!(
+        UnstableGraph::<(), (), Directed, Cyclic, Simple, u16>::try_from(&large).err(),
+        Some(GraphError::GraphFull)
+    );
+}
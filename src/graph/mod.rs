@@ -1,9 +1,286 @@
+pub mod adapters;
+pub mod csr_graph;
+pub mod graph_map;
+pub mod matrix_graph;
 pub mod stable_graph;
+/// Traits that abstract over graffy's graph types.
+pub mod traits;
+pub mod tree;
+mod union_find;
 pub mod unstable_graph;
 
-/// Indicates that an acyclic graph will cycle if an edge is inserted.
-#[derive(Debug)]
-pub struct CycleError;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Debug, Display, Formatter},
+    hash::Hash,
+};
+use traits::{
+    EdgeEndpoints, EdgeIds, GraphBase, GraphProp, Neighbors, VertexIds, VisitMap, Visitable,
+};
+use union_find::UnionFind;
+
+/// An error that is returned by a fallible mutation of a graph, where `N` is the graph's vertex
+/// handle type and `E` is its edge handle type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GraphError<N, E> {
+    /// The handle does not refer to a vertex in the graph.
+    InvalidVertex(N),
+    /// The handle does not refer to an edge in the graph.
+    InvalidEdge(E),
+    /// The graph is acyclic and inserting the edge would close a cycle. The cycle's vertices are
+    /// listed from the edge's target to its source.
+    WouldCycle(Vec<N>),
+    /// The graph does not allow self-loops and the edge would be one at this vertex.
+    SelfLoop(N),
+    /// The graph does not allow parallel edges and these vertices are already connected.
+    ParallelEdge(N, N),
+    /// The graph's index type cannot address another vertex or edge.
+    GraphFull,
+    /// The graph must be a tree and this vertex is its root or already has a parent, so it cannot
+    /// have another one.
+    MultipleParents(N),
+    /// The graph must be a tree and this vertex cannot be reached from its root.
+    Unreachable(N),
+}
+
+impl<N: Debug, E: Debug> Display for GraphError<N, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidVertex(vertex) => {
+                write!(f, "{vertex:?} does not refer to a vertex in the graph")
+            }
+            Self::InvalidEdge(edge) => write!(f, "{edge:?} does not refer to an edge in the graph"),
+            Self::WouldCycle(cycle) => {
+                write!(f, "inserting the edge would close the cycle {cycle:?}")
+            }
+            Self::SelfLoop(vertex) => {
+                write!(f, "the graph does not allow a self-loop at {vertex:?}")
+            }
+            Self::ParallelEdge(source, target) => write!(
+                f,
+                "the graph does not allow another edge between {source:?} and {target:?}"
+            ),
+            Self::GraphFull => write!(f, "the graph cannot hold any more vertices or edges"),
+            Self::MultipleParents(vertex) => {
+                write!(f, "{vertex:?} cannot have more than one parent in a tree")
+            }
+            Self::Unreachable(vertex) => {
+                write!(f, "{vertex:?} cannot be reached from the root of the tree")
+            }
+        }
+    }
+}
+
+impl<N: Debug, E: Debug> std::error::Error for GraphError<N, E> {}
+
+/// A subgraph that was extracted from a graph, paired with the [`SubgraphMap`] between its handles
+/// and the original graph's. A subgraph upholds every invariant of the graph that it was taken
+/// from, so its edges are inserted unchecked.
+pub type Subgraph<G> = (
+    G,
+    SubgraphMap<<G as GraphBase>::VertexId, <G as GraphBase>::EdgeId>,
+);
+
+/// The correspondence between the handles of a graph and those of a subgraph that was extracted
+/// from it, in both directions.
+#[derive(Clone, Debug)]
+pub struct SubgraphMap<N, E> {
+    vertices: HashMap<N, N>,
+    edges: HashMap<E, E>,
+    original_vertices: HashMap<N, N>,
+    original_edges: HashMap<E, E>,
+}
+
+impl<N: Copy + Eq + Hash, E: Copy + Eq + Hash> SubgraphMap<N, E> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        SubgraphMap {
+            vertices: HashMap::new(),
+            edges: HashMap::new(),
+            original_vertices: HashMap::new(),
+            original_edges: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn insert_vertex(&mut self, original: N, vertex: N) {
+        self.vertices.insert(original, vertex);
+        self.original_vertices.insert(vertex, original);
+    }
+
+    #[inline]
+    pub(crate) fn insert_edge(&mut self, original: E, edge: E) {
+        self.edges.insert(original, edge);
+        self.original_edges.insert(edge, original);
+    }
+
+    /// Returns the handle in the subgraph of the vertex `original`, or `None` if it was left out.
+    #[inline]
+    pub fn vertex(&self, original: N) -> Option<N> {
+        self.vertices.get(&original).copied()
+    }
+
+    /// Returns the handle in the subgraph of the edge `original`, or `None` if it was left out.
+    #[inline]
+    pub fn edge(&self, original: E) -> Option<E> {
+        self.edges.get(&original).copied()
+    }
+
+    /// Returns the handle in the original graph of the subgraph's vertex `vertex`.
+    #[inline]
+    pub fn original_vertex(&self, vertex: N) -> Option<N> {
+        self.original_vertices.get(&vertex).copied()
+    }
+
+    /// Returns the handle in the original graph of the subgraph's edge `edge`.
+    #[inline]
+    pub fn original_edge(&self, edge: E) -> Option<E> {
+        self.original_edges.get(&edge).copied()
+    }
+}
+
+/// Finds a shortest path of vertices from `from` to `to` by following [`Neighbors::neighbors`],
+/// including both ends.
+pub(crate) fn path<G: Neighbors + Visitable>(
+    graph: &G,
+    from: G::VertexId,
+    to: G::VertexId,
+) -> Option<Vec<G::VertexId>> {
+    let mut visited = graph.visit_map();
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::from([from]);
+    visited.visit(from);
+
+    while let Some(vertex) = queue.pop_front() {
+        if vertex == to {
+            let mut path = vec![to];
+
+            while let Some(&predecessor) = predecessors.get(path.last().unwrap()) {
+                path.push(predecessor);
+            }
+
+            path.reverse();
+            return Some(path);
+        }
+
+        for neighbour in graph.neighbors(vertex) {
+            if visited.visit(neighbour) {
+                predecessors.insert(neighbour, vertex);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns `true` if `graph` contains a cycle. A directed graph is searched depth-first for an
+/// edge back to a vertex that is still being explored. An undirected graph has a cycle if any of
+/// its edges joins two vertices that its other edges already connect, which includes self-loops
+/// and parallel edges.
+pub(crate) fn has_cycle<G>(graph: &G) -> bool
+where
+    G: GraphProp + VertexIds + EdgeIds + EdgeEndpoints + Neighbors + Visitable,
+{
+    if !graph.is_directed() {
+        let mut components = UnionFind::new();
+
+        return graph.edge_ids().any(|edge| {
+            let (source, target) = graph
+                .edge_endpoints(edge)
+                .expect("Edge without endpoints in graph.");
+            !components.union(source, target)
+        });
+    }
+
+    let mut discovered = graph.visit_map();
+    let mut finished = graph.visit_map();
+
+    for start in graph.vertex_ids() {
+        if !discovered.visit(start) {
+            continue;
+        }
+
+        let mut stack = vec![(start, graph.neighbors(start))];
+
+        while let Some((vertex, neighbours)) = stack.last_mut() {
+            let vertex = *vertex;
+
+            match neighbours.next() {
+                Some(neighbour) if discovered.visit(neighbour) => {
+                    stack.push((neighbour, graph.neighbors(neighbour)));
+                }
+                Some(neighbour) if !finished.is_visited(neighbour) => return true,
+                Some(_) => {}
+                None => {
+                    finished.visit(vertex);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// The connected components of an undirected acyclic graph, which [`would_cycle`] rebuilds on the
+/// next insertion after a removal has reset them to `None`.
+pub(crate) type Components<N> = Option<UnionFind<N>>;
+
+/// Returns the cycle that an edge from `source` to `destination` would close in `graph`, as the
+/// path of vertices from `destination` back to `source`. An undirected graph first consults its
+/// `components`, so that the path is only searched for when one exists.
+pub(crate) fn would_cycle<G>(
+    graph: &G,
+    components: &mut Components<G::VertexId>,
+    source: G::VertexId,
+    destination: G::VertexId,
+) -> Option<Vec<G::VertexId>>
+where
+    G: GraphProp + EdgeIds + EdgeEndpoints + Neighbors + Visitable,
+{
+    if !graph.is_directed() {
+        let components = components.get_or_insert_with(|| {
+            let mut components = UnionFind::new();
+            for edge in graph.edge_ids() {
+                let (source, target) = graph
+                    .edge_endpoints(edge)
+                    .expect("Edge without endpoints in graph.");
+                components.union(source, target);
+            }
+
+            components
+        });
+
+        if components.find(source) != components.find(destination) {
+            return None;
+        }
+    }
+
+    path(graph, destination, source)
+}
+
+/// Checks that an edge from `source` to `destination` is allowed by the edge policy `P` of
+/// `graph`, where `E` is the graph's edge handle type.
+pub(crate) fn check_policy<G: Neighbors, P: EdgePolicy, E>(
+    graph: &G,
+    source: G::VertexId,
+    destination: G::VertexId,
+) -> Result<(), GraphError<G::VertexId, E>> {
+    if !P::allows_self_loops() && source == destination {
+        return Err(GraphError::SelfLoop(source));
+    }
+
+    if !P::allows_parallel_edges()
+        && graph
+            .neighbors(source)
+            .any(|neighbour| neighbour == destination)
+    {
+        return Err(GraphError::ParallelEdge(source, destination));
+    }
+
+    Ok(())
+}
 
 /// An uninhabited type that indicates that a graph's edges are directed.
 #[derive(Clone, Debug)]
@@ -15,19 +292,142 @@ pub enum Undirected {}
 /// A trait for edge types.
 pub trait EdgeType {
     /// Returns `true` if the edges of a graph are directed.
-    fn is_directed(&self) -> bool;
+    ///
+    /// [`Directed`] and [`Undirected`] are uninhabited, so there is never a value to call a method
+    /// on; use [`GraphProp::is_directed`][`traits::GraphProp::is_directed()`] to ask a graph.
+    fn is_directed() -> bool;
 }
 
 impl EdgeType for Directed {
     /// Returns `true` if the edges of a graph are directed. Always returns `true`.
-    fn is_directed(&self) -> bool {
+    fn is_directed() -> bool {
         true
     }
 }
 
 impl EdgeType for Undirected {
     /// Returns true if the edge is directed, always returns `false`.
-    fn is_directed(&self) -> bool {
+    fn is_directed() -> bool {
+        false
+    }
+}
+
+/// The direction of an edge relative to one of its endpoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// An edge that leaves the vertex.
+    Outgoing = 0,
+    /// An edge that enters the vertex.
+    Incoming = 1,
+}
+
+impl Direction {
+    /// Returns the opposite [`Direction`].
+    #[inline]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Outgoing => Self::Incoming,
+            Self::Incoming => Self::Outgoing,
+        }
+    }
+}
+
+/// An uninhabited type that indicates that a graph may contain cycles.
+#[derive(Clone, Debug)]
+pub enum Cyclic {}
+/// An uninhabited type that indicates that a graph may not contain cycles.
+#[derive(Clone, Debug)]
+pub enum Acyclic {}
+
+/// A trait for cyclicness types.
+pub trait Cyclicness {
+    /// Returns `true` if a graph may contain cycles.
+    fn is_cyclic() -> bool;
+}
+
+impl Cyclicness for Cyclic {
+    /// Returns `true` if a graph may contain cycles. Always returns `true`.
+    fn is_cyclic() -> bool {
+        true
+    }
+}
+
+impl Cyclicness for Acyclic {
+    /// Returns `true` if a graph may contain cycles. Always returns `false`.
+    fn is_cyclic() -> bool {
         false
     }
 }
+
+/// An uninhabited type that indicates that a graph may contain neither parallel edges nor
+/// self-loops.
+#[derive(Clone, Debug)]
+pub enum Simple {}
+/// An uninhabited type that indicates that a graph may contain self-loops but not parallel edges.
+#[derive(Clone, Debug)]
+pub enum Looped {}
+/// An uninhabited type that indicates that a graph may contain parallel edges but not self-loops.
+#[derive(Clone, Debug)]
+pub enum Multi {}
+/// An uninhabited type that indicates that a graph may contain both parallel edges and
+/// self-loops.
+#[derive(Clone, Debug)]
+pub enum Pseudo {}
+
+/// A trait for edge policy types, which decide whether a graph may contain parallel edges and
+/// self-loops.
+pub trait EdgePolicy {
+    /// Returns `true` if a graph may contain more than one edge between the same vertices.
+    fn allows_parallel_edges() -> bool;
+
+    /// Returns `true` if a graph may contain edges from a vertex to itself.
+    fn allows_self_loops() -> bool;
+}
+
+impl EdgePolicy for Simple {
+    /// Always returns `false`.
+    fn allows_parallel_edges() -> bool {
+        false
+    }
+
+    /// Always returns `false`.
+    fn allows_self_loops() -> bool {
+        false
+    }
+}
+
+impl EdgePolicy for Looped {
+    /// Always returns `false`.
+    fn allows_parallel_edges() -> bool {
+        false
+    }
+
+    /// Always returns `true`.
+    fn allows_self_loops() -> bool {
+        true
+    }
+}
+
+impl EdgePolicy for Multi {
+    /// Always returns `true`.
+    fn allows_parallel_edges() -> bool {
+        true
+    }
+
+    /// Always returns `false`.
+    fn allows_self_loops() -> bool {
+        false
+    }
+}
+
+impl EdgePolicy for Pseudo {
+    /// Always returns `true`.
+    fn allows_parallel_edges() -> bool {
+        true
+    }
+
+    /// Always returns `true`.
+    fn allows_self_loops() -> bool {
+        true
+    }
+}
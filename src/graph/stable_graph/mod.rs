@@ -1,103 +1,42 @@
-mod dft;
 mod tests;
 
-use std::marker::PhantomData;
-
-use slotmap::{DefaultKey, DenseSlotMap};
-
-use super::CycleError;
-#[derive(Clone, Debug)]
-pub enum Cyclic {}
-#[derive(Clone, Debug)]
-pub enum Acyclic {}
-
-#[derive(Debug)]
-struct InvalidKey;
-
-pub trait Cyclicness: Sized {
-    fn insert_edge<V: Clone, E: Clone, D: EdgeType>(
-        graph: &mut StableGraph<V, E, D, Self>,
-        edge: Edge<E>,
-    ) -> Result<DefaultKey, CycleError>;
-
-    fn is_cyclic() -> bool;
-}
-
-impl Cyclicness for Cyclic {
-    fn insert_edge<V: Clone, E: Clone, D: EdgeType>(
-        graph: &mut StableGraph<V, E, D, Self>,
-        edge: Edge<E>,
-    ) -> Result<DefaultKey, CycleError> {
-        Ok(graph.insert_edge_unchecked(edge))
-    }
-
-    fn is_cyclic() -> bool {
-        true
-    }
-}
-
-impl Cyclicness for Acyclic {
-    fn insert_edge<V: Clone, E: Clone, D: EdgeType>(
-        graph: &mut StableGraph<V, E, D, Self>,
-        edge: Edge<E>,
-    ) -> Result<DefaultKey, CycleError> {
-        if graph.would_cycle_with_edge(edge.clone()) {
-            return Err(CycleError);
-        }
-        Ok(graph.insert_edge_unchecked(edge))
-    }
-
-    fn is_cyclic() -> bool {
-        false
-    }
-}
-
-#[derive(Clone, Debug)]
-pub enum Directed {}
-#[derive(Clone, Debug)]
-pub enum Undirected {}
-
-pub trait EdgeType {
-    fn is_directed(&self) -> bool;
-}
-
-impl EdgeType for Directed {
-    fn is_directed(&self) -> bool {
-        true
-    }
-}
-
-impl EdgeType for Undirected {
-    fn is_directed(&self) -> bool {
-        false
-    }
-}
-
-pub enum Direction {
-    Outgoing,
-    Incoming,
-}
-
-impl Direction {
-    #[inline]
-    pub fn opposite(self) -> Self {
-        match self {
-            Self::Outgoing => Self::Incoming,
-            Self::Incoming => Self::Outgoing,
-        }
-    }
+use std::{
+    iter::Map,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use slotmap::{dense::Keys, new_key_type, DenseSlotMap, SecondaryMap};
+
+use super::{
+    check_policy, has_cycle,
+    traits::{
+        Counts, Data, EdgeEndpoints, EdgeIds, GraphBase, GraphProp, IncidentEdges, Neighbors,
+        VertexIds, Visitable,
+    },
+    would_cycle, Acyclic, Components, Cyclic, Cyclicness, Directed, Direction, EdgePolicy,
+    EdgeType, GraphError, Pseudo, Subgraph, SubgraphMap, Undirected,
+};
+use Direction::{Incoming, Outgoing};
+
+new_key_type! {
+    /// The key of a vertex in a [`StableGraph`].
+    pub struct VertexKey;
+
+    /// The key of an edge in a [`StableGraph`].
+    pub struct EdgeKey;
 }
 
 #[derive(Clone, Debug)]
 pub struct Vertex<V: Clone> {
     pub weight: V,
-    pub edges: Vec<DefaultKey>,
+    edges: [Vec<EdgeKey>; 2],
 }
 
 #[derive(Clone, Debug)]
 pub struct Edge<E: Clone> {
     pub weight: E,
-    pub vertices: [DefaultKey; 2],
+    pub vertices: [VertexKey; 2],
 }
 
 impl<V: Clone> Vertex<V> {
@@ -105,31 +44,27 @@ impl<V: Clone> Vertex<V> {
     fn new(weight: V) -> Vertex<V> {
         Vertex {
             weight,
-            edges: vec![],
+            edges: [vec![], vec![]],
         }
     }
 
-    fn associate_edge(&mut self, key: DefaultKey) {
-        match self.edges.iter().find(|&&idx| idx == key) {
-            Some(_) => return,
-            None => self.edges.push(key),
+    fn associate_edge(&mut self, key: EdgeKey, direction: Direction) {
+        let edges = &mut self.edges[direction as usize];
+
+        if !edges.contains(&key) {
+            edges.push(key);
         }
     }
 
-    fn dissociate_edge(&mut self, key: DefaultKey) {
-        let v = self
-            .edges
-            .iter()
-            .filter(|&&idx| idx != key)
-            .cloned()
-            .collect();
-
-        self.edges = v;
+    fn dissociate_edge(&mut self, key: EdgeKey) {
+        for edges in &mut self.edges {
+            edges.retain(|&idx| idx != key);
+        }
     }
 
     #[inline]
-    pub fn edge_indices(&self) -> &[DefaultKey] {
-        &self.edges
+    pub fn edge_indices(&self, direction: Direction) -> &[EdgeKey] {
+        &self.edges[direction as usize]
     }
 
     #[inline]
@@ -140,76 +75,258 @@ impl<V: Clone> Vertex<V> {
 
 impl<E: Clone> Edge<E> {
     #[inline]
-    fn new(source: DefaultKey, destination: DefaultKey, weight: E) -> Edge<E> {
+    fn new(source: VertexKey, destination: VertexKey, weight: E) -> Edge<E> {
         Edge {
             weight,
             vertices: [source, destination],
         }
     }
 
-    fn vertex_indices(&self) -> (DefaultKey, DefaultKey) {
+    fn vertex_indices(&self) -> (VertexKey, VertexKey) {
         (self.vertices[0], self.vertices[1])
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct StableGraph<V: Clone, E: Clone, D: EdgeType, C: Cyclicness> {
-    pub vertices: DenseSlotMap<DefaultKey, Vertex<V>>,
-    pub edges: DenseSlotMap<DefaultKey, Edge<E>>,
+pub struct StableGraph<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy = Pseudo> {
+    pub vertices: DenseSlotMap<VertexKey, Vertex<V>>,
+    pub edges: DenseSlotMap<EdgeKey, Edge<E>>,
     _directed: PhantomData<D>,
     _cyclic: PhantomData<C>,
+    _policy: PhantomData<P>,
+    components: Components<VertexKey>,
 }
 
-impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness> StableGraph<V, E, D, C> {
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> StableGraph<V, E, D, C, P> {
+    /// Returns `true` if this [`StableGraph`] contains a cycle. In an undirected graph, a
+    /// self-loop or a pair of parallel edges is a cycle.
     pub fn cycles(&self) -> bool {
-        todo!()
-        // Dft::from(self).cycles()
+        has_cycle(self)
     }
 
+    /// Creates an empty [`StableGraph`] with room for `vertex_cap` vertices and `edge_cap` edges,
+    /// whatever its edge type and cyclicness, for subgraphs and copies that already uphold them.
+    pub(crate) fn with_capacities(vertex_cap: usize, edge_cap: usize) -> Self {
+        StableGraph {
+            vertices: DenseSlotMap::with_capacity_and_key(vertex_cap),
+            edges: DenseSlotMap::with_capacity_and_key(edge_cap),
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
+    }
+
+    /// Returns `true` if `key` refers to a vertex in this [`StableGraph`].
     #[inline]
+    pub fn contains_vertex(&self, key: VertexKey) -> bool {
+        self.vertices.contains_key(key)
+    }
+
+    /// Returns the subgraph that consists of the edges at `edges` and their endpoints, along with
+    /// the map between its keys and those of this [`StableGraph`].
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if any key in `edges` does not refer to an edge.
+    pub fn edge_subgraph<I: IntoIterator<Item = EdgeKey>>(
+        &self,
+        edges: I,
+    ) -> Result<Subgraph<Self>, GraphError<VertexKey, EdgeKey>> {
+        let mut kept_vertices = SecondaryMap::new();
+        let mut kept_edges = SecondaryMap::new();
+
+        for key in edges {
+            let edge = self.edges.get(key).ok_or(GraphError::InvalidEdge(key))?;
+
+            kept_edges.insert(key, ());
+            for vertex in edge.vertices {
+                kept_vertices.insert(vertex, ());
+            }
+        }
+
+        Ok(self.subgraph(&kept_vertices, |key| kept_edges.contains_key(key)))
+    }
+
+    /// Returns the subgraph that consists of the vertices at `vertices` and every edge between
+    /// them, along with the map between its keys and those of this [`StableGraph`].
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if any key in `vertices` does not refer to a vertex.
+    pub fn induced_subgraph<I: IntoIterator<Item = VertexKey>>(
+        &self,
+        vertices: I,
+    ) -> Result<Subgraph<Self>, GraphError<VertexKey, EdgeKey>> {
+        let mut kept_vertices = SecondaryMap::new();
+
+        for key in vertices {
+            if !self.contains_vertex(key) {
+                return Err(GraphError::InvalidVertex(key));
+            }
+
+            kept_vertices.insert(key, ());
+        }
+
+        Ok(self.subgraph(&kept_vertices, |_| true))
+    }
+
+    /// Copies the vertices in `kept_vertices` and the edges between them that `keep_edge` accepts
+    /// into a new [`StableGraph`].
+    fn subgraph<F: Fn(EdgeKey) -> bool>(
+        &self,
+        kept_vertices: &SecondaryMap<VertexKey, ()>,
+        keep_edge: F,
+    ) -> Subgraph<Self> {
+        let mut subgraph = Self::with_capacities(kept_vertices.len(), 0);
+        let mut map = SubgraphMap::new();
+
+        for (key, vertex) in &self.vertices {
+            if kept_vertices.contains_key(key) {
+                let new = subgraph.insert_vertex(vertex.weight.clone());
+                map.insert_vertex(key, new);
+            }
+        }
+
+        for (key, edge) in &self.edges {
+            let [source, target] = edge.vertices;
+
+            if let (Some(source), Some(target)) = (map.vertex(source), map.vertex(target)) {
+                if keep_edge(key) {
+                    let new = subgraph.insert_edge_unchecked(Edge::new(
+                        source,
+                        target,
+                        edge.weight.clone(),
+                    ));
+                    map.insert_edge(key, new);
+                }
+            }
+        }
+
+        (subgraph, map)
+    }
+
+    /// Inserts an edge into this [`StableGraph`].
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `source` or `destination` does not refer to a vertex, if the edge would
+    /// be a self-loop or parallel edge that `P` does not allow, or if this [`StableGraph`] is
+    /// acyclic and the edge would introduce a cycle.
     pub fn insert_edge(
         &mut self,
-        source: DefaultKey,
-        destination: DefaultKey,
+        source: VertexKey,
+        destination: VertexKey,
         weight: E,
-    ) -> Result<DefaultKey, CycleError> {
-        C::insert_edge(self, Edge::new(source, destination, weight))
+    ) -> Result<EdgeKey, GraphError<VertexKey, EdgeKey>> {
+        for vertex in [source, destination] {
+            if !self.contains_vertex(vertex) {
+                return Err(GraphError::InvalidVertex(vertex));
+            }
+        }
+
+        check_policy::<_, P, _>(self, source, destination)?;
+
+        if !C::is_cyclic() {
+            let mut components = self.components.take();
+            let cycle = would_cycle(self, &mut components, source, destination);
+            self.components = components;
+
+            if let Some(cycle) = cycle {
+                return Err(GraphError::WouldCycle(cycle));
+            }
+        }
+
+        Ok(self.insert_edge_unchecked(Edge::new(source, destination, weight)))
     }
 
-    fn insert_edge_unchecked(&mut self, edge: Edge<E>) -> DefaultKey {
+    fn insert_edge_unchecked(&mut self, edge: Edge<E>) -> EdgeKey {
         let (from, to) = edge.vertex_indices();
+
+        if let Some(components) = &mut self.components {
+            components.union(from, to);
+        }
         let key = self.edges.insert(edge);
 
-        match self.vertices.get_mut(from) {
-            Some(source_vertex) => {
-                source_vertex.associate_edge(key);
-            }
+        self.vertices[from].associate_edge(key, Outgoing);
+        self.vertices[to].associate_edge(key, Incoming);
 
-            _ => panic!("Invalid key in edge upon insertion into graph."),
-        };
+        key
+    }
 
-        match self.vertices.get_mut(to) {
-            Some(target_vertex) => {
-                target_vertex.associate_edge(key);
-            }
+    #[inline]
+    pub fn insert_vertex(&mut self, weight: V) -> VertexKey {
+        self.vertices.insert(Vertex::new(weight))
+    }
 
-            _ => panic!("Invalid key in edge upon insertion into graph."),
-        };
+    /// Removes the edge at `key` from this [`StableGraph`] and dissociates it from its endpoints,
+    /// returning its weight, or `None` if `key` does not refer to an edge. The keys of all other
+    /// vertices and edges remain valid.
+    pub fn remove_edge(&mut self, key: EdgeKey) -> Option<E> {
+        let edge = self.edges.remove(key)?;
+        self.components = None;
 
-        key
+        for vertex in edge.vertices {
+            self.vertices[vertex].dissociate_edge(key);
+        }
+
+        Some(edge.weight)
+    }
+
+    /// Copies this [`StableGraph`] into one with another cyclicness and edge policy, keeping every
+    /// key. The caller must have checked that the graph upholds them.
+    pub(crate) fn retype<C2: Cyclicness, P2: EdgePolicy>(&self) -> StableGraph<V, E, D, C2, P2> {
+        StableGraph {
+            vertices: self.vertices.clone(),
+            edges: self.edges.clone(),
+            ..StableGraph::with_capacities(0, 0)
+        }
     }
 
+    /// Removes the vertex at `key` and every edge that is incident to it from this
+    /// [`StableGraph`], returning its weight, or `None` if `key` does not refer to a vertex. The
+    /// keys of all other vertices and edges remain valid.
+    pub fn remove_vertex(&mut self, key: VertexKey) -> Option<V> {
+        let vertex = self.vertices.remove(key)?;
+        self.components = None;
+
+        for edge_key in vertex.edges.into_iter().flatten() {
+            // A self-loop is listed twice, so it may already have been removed.
+            if let Some(edge) = self.edges.remove(edge_key) {
+                for endpoint in edge.vertices {
+                    if let Some(endpoint) = self.vertices.get_mut(endpoint) {
+                        endpoint.dissociate_edge(edge_key);
+                    }
+                }
+            }
+        }
+
+        Some(vertex.weight)
+    }
+
+    /// Returns the number of edges that enter the vertex at `key`. For undirected graphs, this is
+    /// the number of edges that are incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to a vertex in this [`StableGraph`].
     #[inline]
-    pub fn insert_vertex(&mut self, weight: V) -> DefaultKey {
-        self.vertices.insert(Vertex {
-            weight,
-            edges: vec![],
-        })
+    pub fn in_degree(&self, key: VertexKey) -> usize {
+        self.degree(key, Incoming)
     }
 
+    /// Returns the number of edges that leave the vertex at `key`. For undirected graphs, this is
+    /// the number of edges that are incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to a vertex in this [`StableGraph`].
     #[inline]
-    pub fn remove_edge(&mut self, key: DefaultKey) -> Result<(), InvalidKey> {
-        self.edges.remove(key).ok_or(InvalidKey).map(|_| ())
+    pub fn out_degree(&self, key: VertexKey) -> usize {
+        self.degree(key, Outgoing)
+    }
+
+    fn degree(&self, key: VertexKey, direction: Direction) -> usize {
+        if D::is_directed() {
+            self.vertices[key].edge_indices(direction).len()
+        } else {
+            self.edges_directed(key, direction).count()
+        }
     }
 
     #[inline]
@@ -221,79 +338,336 @@ impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness> StableGraph<V, E, D, C> {
     pub fn edge_count(&self) -> usize {
         self.edges.len()
     }
+}
 
-    fn would_cycle_with_edge(&mut self, edge: Edge<E>) -> bool {
-        let k = self.edges.insert(edge.clone());
-
-        let cycles = self.cycles();
-        self.edges.remove(k).unwrap();
+impl<V: Clone, E: Clone, P: EdgePolicy> StableGraph<V, E, Directed, Cyclic, P> {
+    #[inline]
+    pub fn new_directed_with_capacity(cap: usize) -> Self {
+        StableGraph {
+            vertices: DenseSlotMap::with_capacity_and_key(cap),
+            edges: DenseSlotMap::with_capacity_and_key(cap),
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
+    }
 
-        cycles
+    #[inline]
+    pub fn new_directed() -> Self {
+        StableGraph {
+            vertices: DenseSlotMap::with_key(),
+            edges: DenseSlotMap::with_key(),
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
     }
 }
 
-impl<V: Clone, E: Clone> StableGraph<V, E, Directed, Cyclic> {
+impl<V: Clone, E: Clone, P: EdgePolicy> StableGraph<V, E, Directed, Acyclic, P> {
     #[inline]
     pub fn new_directed_with_capacity(cap: usize) -> Self {
         StableGraph {
-            vertices: DenseSlotMap::with_capacity(cap),
-            edges: DenseSlotMap::with_capacity(cap),
+            vertices: DenseSlotMap::with_capacity_and_key(cap),
+            edges: DenseSlotMap::with_capacity_and_key(cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
     #[inline]
     pub fn new_directed() -> Self {
         StableGraph {
-            vertices: DenseSlotMap::new(),
-            edges: DenseSlotMap::new(),
+            vertices: DenseSlotMap::with_key(),
+            edges: DenseSlotMap::with_key(),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 }
 
-impl<V: Clone, E: Clone> StableGraph<V, E, Directed, Acyclic> {
+impl<V: Clone, E: Clone, P: EdgePolicy> StableGraph<V, E, Undirected, Cyclic, P> {
     #[inline]
-    pub fn new_directed_with_capacity(cap: usize) -> Self {
+    pub fn new_undirected_with_capacity(cap: usize) -> Self {
         StableGraph {
-            vertices: DenseSlotMap::with_capacity(cap),
-            edges: DenseSlotMap::with_capacity(cap),
+            vertices: DenseSlotMap::with_capacity_and_key(cap),
+            edges: DenseSlotMap::with_capacity_and_key(cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
     #[inline]
-    pub fn new_directed() -> Self {
+    pub fn new_undirected() -> Self {
         StableGraph {
-            vertices: DenseSlotMap::new(),
-            edges: DenseSlotMap::new(),
+            vertices: DenseSlotMap::with_key(),
+            edges: DenseSlotMap::with_key(),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 }
 
-impl<V: Clone, E: Clone> StableGraph<V, E, Undirected, Cyclic> {
+impl<V: Clone, E: Clone, P: EdgePolicy> StableGraph<V, E, Undirected, Acyclic, P> {
     #[inline]
     pub fn new_undirected_with_capacity(cap: usize) -> Self {
         StableGraph {
-            vertices: DenseSlotMap::with_capacity(cap),
-            edges: DenseSlotMap::with_capacity(cap),
+            vertices: DenseSlotMap::with_capacity_and_key(cap),
+            edges: DenseSlotMap::with_capacity_and_key(cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
     #[inline]
     pub fn new_undirected() -> Self {
         StableGraph {
-            vertices: DenseSlotMap::new(),
-            edges: DenseSlotMap::new(),
+            vertices: DenseSlotMap::with_key(),
+            edges: DenseSlotMap::with_key(),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
+    }
+}
+
+/// An iterator over the edges that leave or enter a vertex of a [`StableGraph`], paired with the
+/// vertex at their other end.
+#[derive(Clone, Debug)]
+pub struct Edges<'a, E: Clone> {
+    outgoing: std::slice::Iter<'a, EdgeKey>,
+    incoming: std::slice::Iter<'a, EdgeKey>,
+    skip_loops: bool,
+    edges: &'a DenseSlotMap<EdgeKey, Edge<E>>,
+}
+
+impl<'a, E: Clone> Iterator for Edges<'a, E> {
+    type Item = (EdgeKey, VertexKey);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(&key) = self.outgoing.next() {
+            return Some((key, self.edges[key].vertices[1]));
+        }
+
+        for &key in self.incoming.by_ref() {
+            let [source, target] = self.edges[key].vertices;
+
+            if !self.skip_loops || source != target {
+                return Some((key, source));
+            }
         }
+
+        None
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> GraphBase
+    for StableGraph<V, E, D, C, P>
+{
+    type VertexId = VertexKey;
+    type EdgeId = EdgeKey;
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> GraphProp
+    for StableGraph<V, E, D, C, P>
+{
+    type EdgeType = D;
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> Data
+    for StableGraph<V, E, D, C, P>
+{
+    type VertexWeight = V;
+    type EdgeWeight = E;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: VertexKey) -> Option<&V> {
+        self.vertices.get(vertex).map(Vertex::weight)
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: EdgeKey) -> Option<&E> {
+        self.edges.get(edge).map(|edge| &edge.weight)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> Counts
+    for StableGraph<V, E, D, C, P>
+{
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> VertexIds
+    for StableGraph<V, E, D, C, P>
+{
+    type VertexIds<'a>
+        = Keys<'a, VertexKey, Vertex<V>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        self.vertices.keys()
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> EdgeIds
+    for StableGraph<V, E, D, C, P>
+{
+    type EdgeIds<'a>
+        = Keys<'a, EdgeKey, Edge<E>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> Self::EdgeIds<'_> {
+        self.edges.keys()
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> EdgeEndpoints
+    for StableGraph<V, E, D, C, P>
+{
+    #[inline]
+    fn edge_endpoints(&self, edge: EdgeKey) -> Option<(VertexKey, VertexKey)> {
+        self.edges.get(edge).map(Edge::vertex_indices)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> IncidentEdges
+    for StableGraph<V, E, D, C, P>
+{
+    type Edges<'a>
+        = Edges<'a, E>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` does not refer to a vertex in this [`StableGraph`].
+    #[inline]
+    fn edges_directed(&self, vertex: VertexKey, direction: Direction) -> Edges<'_, E> {
+        let vertex = &self.vertices[vertex];
+        let (outgoing, incoming) = match (D::is_directed(), direction) {
+            (false, _) => (vertex.edge_indices(Outgoing), vertex.edge_indices(Incoming)),
+            (true, Outgoing) => (vertex.edge_indices(Outgoing), &[][..]),
+            (true, Incoming) => (&[][..], vertex.edge_indices(Incoming)),
+        };
+
+        Edges {
+            outgoing: outgoing.iter(),
+            incoming: incoming.iter(),
+            skip_loops: !D::is_directed(),
+            edges: &self.edges,
+        }
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> Neighbors
+    for StableGraph<V, E, D, C, P>
+{
+    type Neighbors<'a>
+        = Map<Edges<'a, E>, fn((EdgeKey, VertexKey)) -> VertexKey>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` does not refer to a vertex in this [`StableGraph`].
+    #[inline]
+    fn neighbors_directed(&self, vertex: VertexKey, direction: Direction) -> Self::Neighbors<'_> {
+        self.edges_directed(vertex, direction)
+            .map(|(_, neighbour)| neighbour)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> Visitable
+    for StableGraph<V, E, D, C, P>
+{
+    type Map = SecondaryMap<VertexKey, ()>;
+
+    #[inline]
+    fn visit_map(&self) -> Self::Map {
+        SecondaryMap::with_capacity(self.vertices.len())
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> Index<VertexKey>
+    for StableGraph<V, E, D, C, P>
+{
+    type Output = V;
+
+    /// Returns the weight of the vertex at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to a vertex in this [`StableGraph`].
+    #[inline]
+    fn index(&self, key: VertexKey) -> &V {
+        &self.vertices[key].weight
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> IndexMut<VertexKey>
+    for StableGraph<V, E, D, C, P>
+{
+    /// Returns the weight of the vertex at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to a vertex in this [`StableGraph`].
+    #[inline]
+    fn index_mut(&mut self, key: VertexKey) -> &mut V {
+        &mut self.vertices[key].weight
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> Index<EdgeKey>
+    for StableGraph<V, E, D, C, P>
+{
+    type Output = E;
+
+    /// Returns the weight of the edge at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to an edge in this [`StableGraph`].
+    #[inline]
+    fn index(&self, key: EdgeKey) -> &E {
+        &self.edges[key].weight
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy> IndexMut<EdgeKey>
+    for StableGraph<V, E, D, C, P>
+{
+    /// Returns the weight of the edge at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to an edge in this [`StableGraph`].
+    #[inline]
+    fn index_mut(&mut self, key: EdgeKey) -> &mut E {
+        &mut self.edges[key].weight
     }
 }
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::Simple;
+
+#[test]
+fn remove() {
+    let mut graph = StableGraph::<char, u32, Directed, Cyclic>::new_directed();
+    let [a, b, c] = ['a', 'b', 'c'].map(|weight| graph.insert_vertex(weight));
+    let ab = graph.insert_edge(a, b, 1).unwrap();
+    let bc = graph.insert_edge(b, c, 2).unwrap();
+    let ca = graph.insert_edge(c, a, 3).unwrap();
+    let bb = graph.insert_edge(b, b, 4).unwrap();
+
+    assert_eq!(graph.remove_edge(ab), Some(1));
+    assert_eq!(graph.remove_edge(ab), None);
+    assert!(graph.vertices[a].edge_indices(Outgoing).is_empty());
+    assert_eq!(graph.vertices[b].edge_indices(Incoming), [bb]);
+
+    assert_eq!(graph.remove_vertex(b), Some('b'));
+    assert_eq!(graph.remove_vertex(b), None);
+    assert_eq!(graph.edge_count(), 1);
+    assert!(!graph.edges.contains_key(bc));
+    assert_eq!(graph.vertices[c].edge_indices(Incoming), []);
+    assert_eq!(graph.vertices[c].edge_indices(Outgoing), [ca]);
+    assert_eq!(graph.vertices[a].weight, 'a');
+}
+
+#[test]
+fn insert_edge_errors() {
+    let mut graph = StableGraph::<(), (), Directed, Acyclic>::new_directed();
+    let [a, b] = [(); 2].map(|_| graph.insert_vertex(()));
+    graph.insert_edge(a, b, ()).unwrap();
+
+    assert_eq!(
+        graph.insert_edge(b, a, ()),
+        Err(GraphError::WouldCycle(vec![a, b]))
+    );
+
+    graph.remove_vertex(b);
+    assert_eq!(
+        graph.insert_edge(a, b, ()),
+        Err(GraphError::InvalidVertex(b))
+    );
+}
+
+#[test]
+fn edge_policies() {
+    let mut graph = StableGraph::<(), (), Directed, Cyclic, Simple>::new_directed();
+    let [a, b] = [(); 2].map(|_| graph.insert_vertex(()));
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(b, a, ()).unwrap();
+
+    assert_eq!(
+        graph.insert_edge(a, b, ()),
+        Err(GraphError::ParallelEdge(a, b))
+    );
+    assert_eq!(graph.insert_edge(b, b, ()), Err(GraphError::SelfLoop(b)));
+
+    // Removing an edge makes room for it again.
+    let ab = graph.edges_directed(a, Outgoing).next().unwrap().0;
+    graph.remove_edge(ab);
+    assert!(graph.insert_edge(a, b, ()).is_ok());
+}
+
+#[test]
+fn forest() {
+    let mut graph = StableGraph::<(), (), Undirected, Acyclic>::new_undirected();
+    let [a, b, c] = [(); 3].map(|_| graph.insert_vertex(()));
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(b, c, ()).unwrap();
+
+    assert_eq!(
+        graph.insert_edge(c, a, ()),
+        Err(GraphError::WouldCycle(vec![a, b, c]))
+    );
+    assert!(!graph.cycles());
+
+    graph.remove_vertex(b);
+    graph.insert_edge(c, a, ()).unwrap();
+    assert!(!graph.cycles());
+}
+
+#[test]
+fn subgraphs() {
+    let mut graph = StableGraph::<char, u32, Undirected, Acyclic>::new_undirected();
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|weight| graph.insert_vertex(weight));
+    let ab = graph.insert_edge(a, b, 1).unwrap();
+    let bc = graph.insert_edge(b, c, 2).unwrap();
+    graph.insert_edge(c, d, 3).unwrap();
+
+    let (induced, map) = graph.induced_subgraph([a, b, d]).unwrap();
+    assert_eq!(induced.vertex_count(), 3);
+    assert_eq!(induced.edge_count(), 1);
+    assert_eq!(induced[map.vertex(d).unwrap()], 'd');
+    assert_eq!(map.original_edge(map.edge(ab).unwrap()), Some(ab));
+    assert_eq!(map.edge(bc), None);
+
+    let (mut edges, map) = graph.edge_subgraph([bc, ab]).unwrap();
+    assert_eq!(edges.vertex_count(), 3);
+    assert_eq!(edges.edge_count(), 2);
+    assert_eq!(map.vertex(d), None);
+
+    let [a, c] = [a, c].map(|vertex| map.vertex(vertex).unwrap());
+    assert!(matches!(
+        edges.insert_edge(c, a, 0),
+        Err(GraphError::WouldCycle(_))
+    ));
+
+    graph.remove_vertex(d);
+    assert_eq!(
+        graph.induced_subgraph([d]).err(),
+        Some(GraphError::InvalidVertex(d))
+    );
+}
@@ -0,0 +1,191 @@
+mod tests;
+
+use super::{Direction, EdgeType};
+use slotmap::{Key, SecondaryMap};
+use std::{collections::HashSet, fmt::Debug, hash::Hash};
+
+/// The handle types of a graph's vertices and edges.
+pub trait GraphBase {
+    /// The handle type of this graph's vertices.
+    type VertexId: Copy + Eq + Hash + Debug;
+    /// The handle type of this graph's edges.
+    type EdgeId: Copy + Eq + Hash + Debug;
+}
+
+/// The edge type of a graph.
+pub trait GraphProp: GraphBase {
+    /// Whether this graph's edges are [`Directed`][`super::Directed`] or
+    /// [`Undirected`][`super::Undirected`].
+    type EdgeType: EdgeType;
+
+    /// Returns `true` if this graph's edges are directed.
+    #[inline]
+    fn is_directed(&self) -> bool {
+        Self::EdgeType::is_directed()
+    }
+}
+
+/// Access to the weights of a graph's vertices and edges.
+pub trait Data: GraphBase {
+    /// The weight type of this graph's vertices.
+    type VertexWeight;
+    /// The weight type of this graph's edges.
+    type EdgeWeight;
+
+    /// Returns the weight of `vertex`, or `None` if it is not in this graph.
+    fn vertex_weight(&self, vertex: Self::VertexId) -> Option<&Self::VertexWeight>;
+
+    /// Returns the weight of `edge`, or `None` if it is not in this graph.
+    fn edge_weight(&self, edge: Self::EdgeId) -> Option<&Self::EdgeWeight>;
+}
+
+/// The number of vertices and edges in a graph.
+pub trait Counts: GraphBase {
+    /// Returns the number of vertices in this graph.
+    fn vertex_count(&self) -> usize;
+
+    /// Returns the number of edges in this graph.
+    fn edge_count(&self) -> usize;
+}
+
+/// Iteration over the handles of a graph's vertices.
+pub trait VertexIds: GraphBase {
+    /// The iterator returned by [`vertex_ids`][`Self::vertex_ids()`].
+    type VertexIds<'a>: Iterator<Item = Self::VertexId>
+    where
+        Self: 'a;
+
+    /// Returns an iterator over the handle of every vertex in this graph.
+    fn vertex_ids(&self) -> Self::VertexIds<'_>;
+}
+
+/// Iteration over the handles of a graph's edges.
+pub trait EdgeIds: GraphBase {
+    /// The iterator returned by [`edge_ids`][`Self::edge_ids()`].
+    type EdgeIds<'a>: Iterator<Item = Self::EdgeId>
+    where
+        Self: 'a;
+
+    /// Returns an iterator over the handle of every edge in this graph.
+    fn edge_ids(&self) -> Self::EdgeIds<'_>;
+}
+
+/// The vertices that a graph's edges connect.
+pub trait EdgeEndpoints: GraphBase {
+    /// Returns the source and target of `edge`, or `None` if it is not in this graph. For
+    /// undirected graphs, the order is the one that the edge was inserted with.
+    fn edge_endpoints(&self, edge: Self::EdgeId) -> Option<(Self::VertexId, Self::VertexId)>;
+}
+
+/// The vertices that are adjacent to a vertex.
+pub trait Neighbors: GraphBase {
+    /// The iterator returned by [`neighbors_directed`][`Self::neighbors_directed()`].
+    type Neighbors<'a>: Iterator<Item = Self::VertexId>
+    where
+        Self: 'a;
+
+    /// Returns an iterator over the vertices that `vertex` has an edge to. For undirected graphs,
+    /// this is every vertex that shares an edge with `vertex`. A vertex is yielded once for each
+    /// edge that connects it to `vertex`.
+    #[inline]
+    fn neighbors(&self, vertex: Self::VertexId) -> Self::Neighbors<'_> {
+        self.neighbors_directed(vertex, Direction::Outgoing)
+    }
+
+    /// Returns an iterator over the vertices at the other end of the edges that leave or enter
+    /// `vertex`, depending on `direction`. For undirected graphs, `direction` is ignored.
+    fn neighbors_directed(
+        &self,
+        vertex: Self::VertexId,
+        direction: Direction,
+    ) -> Self::Neighbors<'_>;
+}
+
+/// The edges that are incident to a vertex.
+pub trait IncidentEdges: GraphBase {
+    /// The iterator returned by [`edges_directed`][`Self::edges_directed()`].
+    type Edges<'a>: Iterator<Item = (Self::EdgeId, Self::VertexId)>
+    where
+        Self: 'a;
+
+    /// Returns an iterator over the edges that leave `vertex`, paired with the vertex at their
+    /// other end. For undirected graphs, this is every edge that is incident to `vertex`.
+    #[inline]
+    fn edges(&self, vertex: Self::VertexId) -> Self::Edges<'_> {
+        self.edges_directed(vertex, Direction::Outgoing)
+    }
+
+    /// Returns an iterator over the edges that leave or enter `vertex`, depending on `direction`,
+    /// paired with the vertex at their other end. For undirected graphs, `direction` is ignored
+    /// and a self-loop is yielded once.
+    fn edges_directed(&self, vertex: Self::VertexId, direction: Direction) -> Self::Edges<'_>;
+}
+
+/// A set of vertices that a traversal has visited.
+pub trait VisitMap<N> {
+    /// Marks `vertex` as visited, returning `true` if it had not been visited before.
+    fn visit(&mut self, vertex: N) -> bool;
+
+    /// Returns `true` if `vertex` has been visited.
+    fn is_visited(&self, vertex: N) -> bool;
+}
+
+/// Graphs that can create a [`VisitMap`] suited to their vertex handles.
+pub trait Visitable: GraphBase {
+    /// The visit map type of this graph.
+    type Map: VisitMap<Self::VertexId>;
+
+    /// Creates an empty visit map for this graph.
+    fn visit_map(&self) -> Self::Map;
+
+    /// Clears `map` and prepares it for another traversal of this graph.
+    fn reset_map(&self, map: &mut Self::Map);
+}
+
+/// A graph that graffy's algorithms can run on.
+///
+/// This trait is implemented for every type that implements its supertraits, so a custom graph
+/// storage only needs to implement those to be usable with [`algo`][`crate::algo`].
+pub trait Graph: GraphProp + Data + Counts + VertexIds + EdgeIds + EdgeEndpoints {}
+
+impl<G: GraphProp + Data + Counts + VertexIds + EdgeIds + EdgeEndpoints + ?Sized> Graph for G {}
+
+impl VisitMap<usize> for Vec<bool> {
+    #[inline]
+    fn visit(&mut self, vertex: usize) -> bool {
+        if vertex >= self.len() {
+            self.resize(vertex + 1, false);
+        }
+
+        !std::mem::replace(&mut self[vertex], true)
+    }
+
+    #[inline]
+    fn is_visited(&self, vertex: usize) -> bool {
+        self.get(vertex).copied().unwrap_or(false)
+    }
+}
+
+impl<N: Eq + Hash> VisitMap<N> for HashSet<N> {
+    #[inline]
+    fn visit(&mut self, vertex: N) -> bool {
+        self.insert(vertex)
+    }
+
+    #[inline]
+    fn is_visited(&self, vertex: N) -> bool {
+        self.contains(&vertex)
+    }
+}
+
+impl<K: Key> VisitMap<K> for SecondaryMap<K, ()> {
+    #[inline]
+    fn visit(&mut self, vertex: K) -> bool {
+        self.insert(vertex, ()).is_none()
+    }
+
+    #[inline]
+    fn is_visited(&self, vertex: K) -> bool {
+        self.contains_key(vertex)
+    }
+}
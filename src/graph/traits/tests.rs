@@ -0,0 +1,324 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    algo::bipartite::is_bipartite,
+    graph::{
+        stable_graph::StableGraph, unstable_graph::UnstableGraph, Cyclic, Directed, Direction,
+        Undirected,
+    },
+};
+use std::{collections::HashMap, ops::Range};
+use Direction::{Incoming, Outgoing};
+
+/// An undirected graph that is stored as a list of edges and that only implements graffy's traits.
+struct EdgeList {
+    vertices: Vec<&'static str>,
+    edges: Vec<(u32, u32)>,
+}
+
+impl GraphBase for EdgeList {
+    type VertexId = u32;
+    type EdgeId = usize;
+}
+
+impl GraphProp for EdgeList {
+    type EdgeType = Undirected;
+}
+
+impl Data for EdgeList {
+    type VertexWeight = &'static str;
+    type EdgeWeight = (u32, u32);
+
+    fn vertex_weight(&self, vertex: u32) -> Option<&&'static str> {
+        self.vertices.get(vertex as usize)
+    }
+
+    fn edge_weight(&self, edge: usize) -> Option<&(u32, u32)> {
+        self.edges.get(edge)
+    }
+}
+
+impl Counts for EdgeList {
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl VertexIds for EdgeList {
+    type VertexIds<'a> = Range<u32>;
+
+    fn vertex_ids(&self) -> Range<u32> {
+        0..self.vertices.len() as u32
+    }
+}
+
+impl EdgeIds for EdgeList {
+    type EdgeIds<'a> = Range<usize>;
+
+    fn edge_ids(&self) -> Range<usize> {
+        0..self.edges.len()
+    }
+}
+
+impl EdgeEndpoints for EdgeList {
+    fn edge_endpoints(&self, edge: usize) -> Option<(u32, u32)> {
+        self.edges.get(edge).copied()
+    }
+}
+
+#[test]
+fn custom_storage() {
+    let square = EdgeList {
+        vertices: vec!["a", "b", "c", "d"],
+        edges: vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+    };
+    let bipartition = is_bipartite(&square).unwrap();
+    assert_eq!(bipartition.left.len(), 2);
+
+    let triangle = EdgeList {
+        vertices: vec!["a", "b", "c"],
+        edges: vec![(0, 1), (1, 2), (2, 0)],
+    };
+    assert_eq!(is_bipartite(&triangle).unwrap_err().0.len(), 3);
+}
+
+#[test]
+fn neighbors() {
+    let mut graph = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let [a, b, c] = [(); 3].map(|_| graph.insert_vertex(()).unwrap());
+    let ab = graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(c, a, ()).unwrap();
+    let aa = graph.insert_edge(a, a, ()).unwrap();
+
+    assert_eq!(graph.neighbors(a).collect::<Vec<_>>(), [b, a]);
+    assert_eq!(graph.edges(a).collect::<Vec<_>>(), [(ab, b), (aa, a)]);
+    assert_eq!(graph.neighbors(b).count(), 0);
+    assert_eq!(
+        graph
+            .neighbors_directed(a, Direction::Incoming)
+            .collect::<Vec<_>>(),
+        [c, a]
+    );
+    assert_eq!((graph.in_degree(a), graph.out_degree(a)), (2, 2));
+    assert_eq!((graph.in_degree(b), graph.out_degree(b)), (1, 0));
+
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let [a, b, c] = [(); 3].map(|_| graph.insert_vertex(()));
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(c, a, ()).unwrap();
+    let aa = graph.insert_edge(a, a, ()).unwrap();
+
+    assert_eq!(graph.neighbors(a).collect::<Vec<_>>(), [b, a, c]);
+    assert_eq!(graph.edges(a).nth(1), Some((aa, a)));
+    assert_eq!(graph.neighbors(b).collect::<Vec<_>>(), [a]);
+    assert_eq!((graph.in_degree(a), graph.out_degree(a)), (3, 3));
+    assert_eq!(
+        graph.vertex_ids().collect::<Vec<_>>(),
+        graph.vertices.keys().collect::<Vec<_>>()
+    );
+}
+
+/// The edges `a → b`, `a → c`, `c → a` and the self-loop `b → b` between the vertices `[a, b, c]`.
+const DIRECTION_EDGES: [(usize, usize); 4] = [(0, 1), (0, 2), (2, 0), (1, 1)];
+
+/// Returns how many times each neighbour of `vertex` in `direction` is listed, after checking that
+/// `neighbors_directed` and `edges_directed` list the same neighbours.
+fn neighbour_counts<G: Neighbors + IncidentEdges>(
+    graph: &G,
+    vertex: G::VertexId,
+    direction: Direction,
+) -> HashMap<G::VertexId, usize> {
+    let neighbours = graph
+        .neighbors_directed(vertex, direction)
+        .collect::<Vec<_>>();
+    let ends = graph
+        .edges_directed(vertex, direction)
+        .map(|(_, neighbour)| neighbour)
+        .collect::<Vec<_>>();
+    assert_eq!(neighbours, ends);
+
+    let mut counts = HashMap::new();
+    for neighbour in neighbours {
+        *counts.entry(neighbour).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Checks the adjacency of a graph with the [`DIRECTION_EDGES`] between `[a, b, c]`, whose
+/// `(in, out)` degrees are reported by `degrees`.
+fn check_directions<G: GraphProp + Neighbors + IncidentEdges>(
+    graph: &G,
+    [a, b, c]: [G::VertexId; 3],
+    degrees: impl Fn(G::VertexId) -> (usize, usize),
+) {
+    let counts = |vertex, direction| neighbour_counts(graph, vertex, direction);
+    let expected =
+        |pairs: &[(G::VertexId, usize)]| pairs.iter().copied().collect::<HashMap<_, _>>();
+
+    if graph.is_directed() {
+        assert_eq!(counts(a, Outgoing), expected(&[(b, 1), (c, 1)]));
+        assert_eq!(counts(a, Incoming), expected(&[(c, 1)]));
+        assert_eq!(graph.neighbors(a).count(), 2);
+        assert_eq!(degrees(a), (1, 2));
+
+        // A directed self-loop both leaves and enters its vertex.
+        assert_eq!(counts(b, Outgoing), expected(&[(b, 1)]));
+        assert_eq!(counts(b, Incoming), expected(&[(a, 1), (b, 1)]));
+        assert_eq!(degrees(b), (2, 1));
+    } else {
+        for vertex in [a, b, c] {
+            assert_eq!(counts(vertex, Outgoing), counts(vertex, Incoming));
+            assert_eq!(degrees(vertex).0, degrees(vertex).1);
+        }
+
+        assert_eq!(counts(a, Outgoing), expected(&[(b, 1), (c, 2)]));
+        assert_eq!(counts(c, Incoming), expected(&[(a, 2)]));
+
+        // An undirected self-loop is listed and counted once, as `in_degree` documents.
+        assert_eq!(counts(b, Outgoing), expected(&[(a, 1), (b, 1)]));
+        assert_eq!(degrees(b), (2, 2));
+    }
+}
+
+#[test]
+fn directions() {
+    let mut graph = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let v = [(); 3].map(|_| graph.insert_vertex(()).unwrap());
+    for (source, target) in DIRECTION_EDGES {
+        graph.insert_edge(v[source], v[target], ()).unwrap();
+    }
+    check_directions(&graph, v, |x| (graph.in_degree(x), graph.out_degree(x)));
+
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = [(); 3].map(|_| graph.insert_vertex(()).unwrap());
+    for (source, target) in DIRECTION_EDGES {
+        graph.insert_edge(v[source], v[target], ()).unwrap();
+    }
+    check_directions(&graph, v, |x| (graph.in_degree(x), graph.out_degree(x)));
+
+    let mut graph = StableGraph::<(), (), Directed, Cyclic>::new_directed();
+    let v = [(); 3].map(|_| graph.insert_vertex(()));
+    for (source, target) in DIRECTION_EDGES {
+        graph.insert_edge(v[source], v[target], ()).unwrap();
+    }
+    check_directions(&graph, v, |x| (graph.in_degree(x), graph.out_degree(x)));
+
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let v = [(); 3].map(|_| graph.insert_vertex(()));
+    for (source, target) in DIRECTION_EDGES {
+        graph.insert_edge(v[source], v[target], ()).unwrap();
+    }
+    check_directions(&graph, v, |x| (graph.in_degree(x), graph.out_degree(x)));
+}
+
+/// Checks that every edge is listed by exactly its endpoints, and that an undirected graph lists
+/// the same neighbours in both directions.
+fn check_adjacency<G: Graph + Neighbors + IncidentEdges>(graph: &G) {
+    let mut listed = HashMap::<_, usize>::new();
+
+    for vertex in graph.vertex_ids() {
+        for (edge, neighbour) in graph.edges_directed(vertex, Outgoing) {
+            let ends = graph.edge_endpoints(edge).unwrap();
+            assert!(
+                ends == (vertex, neighbour) || !graph.is_directed() && ends == (neighbour, vertex)
+            );
+            *listed.entry(edge).or_default() += 1;
+        }
+
+        for (edge, neighbour) in graph.edges_directed(vertex, Incoming) {
+            let ends = graph.edge_endpoints(edge).unwrap();
+            assert!(
+                ends == (neighbour, vertex) || !graph.is_directed() && ends == (vertex, neighbour)
+            );
+        }
+
+        if !graph.is_directed() {
+            assert_eq!(
+                neighbour_counts(graph, vertex, Outgoing),
+                neighbour_counts(graph, vertex, Incoming)
+            );
+        }
+    }
+
+    // An undirected edge leaves both of its endpoints unless it is a self-loop.
+    for edge in graph.edge_ids() {
+        let (source, target) = graph.edge_endpoints(edge).unwrap();
+        let expected = if graph.is_directed() || source == target {
+            1
+        } else {
+            2
+        };
+        assert_eq!(listed.remove(&edge), Some(expected));
+    }
+
+    assert!(listed.is_empty());
+}
+
+#[test]
+fn adjacency_after_removal() {
+    let edges = [(0, 1), (0, 2), (2, 0), (1, 1), (2, 3), (3, 1)];
+
+    let mut graph = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    let v = [(); 4].map(|_| graph.insert_vertex(()).unwrap());
+    let e = edges.map(|(source, target)| graph.insert_edge(v[source], v[target], ()).unwrap());
+    graph.remove_edge(e[1]);
+    check_adjacency(&graph);
+    graph.remove_vertex(v[1]);
+    check_adjacency(&graph);
+    assert_eq!(graph.edge_count(), 2);
+
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let v = [(); 4].map(|_| graph.insert_vertex(()).unwrap());
+    let e = edges.map(|(source, target)| graph.insert_edge(v[source], v[target], ()).unwrap());
+    graph.remove_edge(e[0]);
+    check_adjacency(&graph);
+    graph.remove_vertex(v[2]);
+    check_adjacency(&graph);
+    assert_eq!(graph.edge_count(), 2);
+
+    let mut graph = StableGraph::<(), (), Directed, Cyclic>::new_directed();
+    let v = [(); 4].map(|_| graph.insert_vertex(()));
+    let e = edges.map(|(source, target)| graph.insert_edge(v[source], v[target], ()).unwrap());
+    graph.remove_edge(e[1]);
+    check_adjacency(&graph);
+    graph.remove_vertex(v[1]);
+    check_adjacency(&graph);
+    assert_eq!(graph.edge_count(), 2);
+
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let v = [(); 4].map(|_| graph.insert_vertex(()));
+    let e = edges.map(|(source, target)| graph.insert_edge(v[source], v[target], ()).unwrap());
+    graph.remove_edge(e[0]);
+    check_adjacency(&graph);
+    graph.remove_vertex(v[2]);
+    check_adjacency(&graph);
+    assert_eq!(graph.edge_count(), 2);
+}
+
+#[test]
+fn visit_maps() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let [a, b] = [(); 2].map(|_| graph.insert_vertex(()).unwrap());
+    let mut map = graph.visit_map();
+
+    assert!(map.visit(a));
+    assert!(!map.visit(a));
+    assert!(!map.is_visited(b));
+    graph.reset_map(&mut map);
+    assert!(!map.is_visited(a));
+
+    let mut graph = StableGraph::<(), (), Undirected, Cyclic>::new_undirected();
+    let a = graph.insert_vertex(());
+    let mut map = graph.visit_map();
+
+    assert!(map.visit(a));
+    assert!(map.is_visited(a));
+    graph.reset_map(&mut map);
+    assert!(!map.is_visited(a));
+}
@@ -0,0 +1,630 @@
+mod tests;
+
+use super::{
+    stable_graph::{EdgeKey, StableGraph, VertexKey},
+    traits::{
+        Counts, Data, EdgeEndpoints, EdgeIds, Graph, GraphBase, GraphProp, IncidentEdges,
+        Neighbors, VertexIds, VisitMap, Visitable,
+    },
+    unstable_graph::{Edge, EdgeIndex, IndexType, UnstableGraph, VertexIndex},
+    Acyclic, Cyclicness, Directed, Direction, EdgePolicy, GraphError, Simple,
+};
+use std::ops::{Index, IndexMut};
+
+/// A [`Tree`] backed by an [`UnstableGraph`]. It is compact, but can only grow.
+pub type UnstableTree<V, E, Ix = usize> = Tree<UnstableGraph<V, E, Directed, Acyclic, Simple, Ix>>;
+
+/// A [`Tree`] backed by a [`StableGraph`]. Its subtrees can be removed without invalidating the
+/// keys of the remaining vertices and edges.
+pub type StableTree<V, E> = Tree<StableGraph<V, E, Directed, Acyclic, Simple>>;
+
+/// An iterator over the children of a vertex of a [`Tree`].
+pub type Children<'a, G> = <G as Neighbors>::Neighbors<'a>;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A graph that can back a [`Tree`]. It is implemented for the directed, acyclic and simple
+/// [`UnstableGraph`] and [`StableGraph`].
+pub trait TreeGraph:
+    Graph + GraphProp<EdgeType = Directed> + Neighbors + IncidentEdges + Visitable + sealed::Sealed
+{
+    /// Creates a graph that consists of a single vertex with the weight `root` and returns it
+    /// along with that vertex.
+    fn with_root(root: Self::VertexWeight) -> (Self, Self::VertexId)
+    where
+        Self: Sized;
+
+    /// Inserts a vertex with the weight `weight` and an edge with the weight `edge` from `parent`
+    /// to it, returning the new vertex.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `parent` is not in this graph or if it cannot hold another vertex or
+    /// edge.
+    fn insert_leaf(
+        &mut self,
+        parent: Self::VertexId,
+        weight: Self::VertexWeight,
+        edge: Self::EdgeWeight,
+    ) -> Result<Self::VertexId, GraphError<Self::VertexId, Self::EdgeId>>;
+}
+
+impl<V: Clone, E: Clone, Ix: IndexType> sealed::Sealed
+    for UnstableGraph<V, E, Directed, Acyclic, Simple, Ix>
+{
+}
+
+impl<V: Clone, E: Clone, Ix: IndexType> TreeGraph
+    for UnstableGraph<V, E, Directed, Acyclic, Simple, Ix>
+{
+    fn with_root(root: V) -> (Self, VertexIndex<Ix>) {
+        let mut graph = Self::with_capacities(1, 0);
+        let root = graph.insert_vertex_unchecked(root);
+
+        (graph, root)
+    }
+
+    fn insert_leaf(
+        &mut self,
+        parent: VertexIndex<Ix>,
+        weight: V,
+        edge: E,
+    ) -> Result<VertexIndex<Ix>, GraphError<VertexIndex<Ix>, EdgeIndex<Ix>>> {
+        if !self.contains_vertex(parent) {
+            return Err(GraphError::InvalidVertex(parent));
+        }
+
+        if self.edge_count() > Ix::MAX {
+            return Err(GraphError::GraphFull);
+        }
+
+        // A new leaf has no edges, so the edge to it can neither close a cycle nor repeat one.
+        let child = self.insert_vertex(weight)?;
+        self.insert_edge_unchecked(Edge::new(parent, child, edge));
+
+        Ok(child)
+    }
+}
+
+impl<V: Clone, E: Clone> sealed::Sealed for StableGraph<V, E, Directed, Acyclic, Simple> {}
+
+impl<V: Clone, E: Clone> TreeGraph for StableGraph<V, E, Directed, Acyclic, Simple> {
+    fn with_root(root: V) -> (Self, VertexKey) {
+        let mut graph = Self::new_directed();
+        let root = graph.insert_vertex(root);
+
+        (graph, root)
+    }
+
+    fn insert_leaf(
+        &mut self,
+        parent: VertexKey,
+        weight: V,
+        edge: E,
+    ) -> Result<VertexKey, GraphError<VertexKey, EdgeKey>> {
+        if !self.contains_vertex(parent) {
+            return Err(GraphError::InvalidVertex(parent));
+        }
+
+        let child = self.insert_vertex(weight);
+        self.insert_edge(parent, child, edge)?;
+
+        Ok(child)
+    }
+}
+
+/// A rooted tree, in which every vertex but the root has exactly one parent and can be reached
+/// from the root. Edges lead from parents to their children. A [`Tree`] can only change through
+/// its own methods, so it is a tree at all times; an existing graph can be checked and turned
+/// into one with `from_graph`.
+///
+/// A [`Tree`] is generic over the graph that backs it, which is usually chosen through
+/// [`UnstableTree`] or [`StableTree`].
+#[derive(Clone, Debug)]
+pub struct Tree<G: TreeGraph> {
+    graph: G,
+    root: G::VertexId,
+}
+
+impl<G: TreeGraph> Tree<G> {
+    /// Creates a [`Tree`] that consists of a root with the weight `root`.
+    pub fn new(root: G::VertexWeight) -> Self {
+        let (graph, root) = G::with_root(root);
+
+        Tree { graph, root }
+    }
+
+    /// Returns the ancestors of `vertex`, from its parent up to the root.
+    #[inline]
+    pub fn ancestors(&self, vertex: G::VertexId) -> Ancestors<'_, G> {
+        Ancestors {
+            tree: self,
+            next: self.parent(vertex),
+        }
+    }
+
+    /// Returns the children of `vertex` in the order that they were inserted.
+    ///
+    /// # Panics:
+    /// Panics if `vertex` is not in this [`Tree`].
+    #[inline]
+    pub fn children(&self, vertex: G::VertexId) -> Children<'_, G> {
+        self.graph.neighbors(vertex)
+    }
+
+    /// Returns the number of edges between the root and `vertex`, or `None` if it is not in this
+    /// [`Tree`].
+    #[inline]
+    pub fn depth(&self, vertex: G::VertexId) -> Option<usize> {
+        self.graph.vertex_weight(vertex)?;
+        Some(self.ancestors(vertex).count())
+    }
+
+    /// Returns the graph that backs this [`Tree`].
+    #[inline]
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Inserts a vertex with the weight `weight` as the last child of `parent`, joined to it by
+    /// an edge with the weight `edge`.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `parent` is not in this [`Tree`] or if the backing graph cannot hold
+    /// another vertex or edge.
+    #[inline]
+    pub fn insert_child(
+        &mut self,
+        parent: G::VertexId,
+        weight: G::VertexWeight,
+        edge: G::EdgeWeight,
+    ) -> Result<G::VertexId, GraphError<G::VertexId, G::EdgeId>> {
+        self.graph.insert_leaf(parent, weight, edge)
+    }
+
+    /// Returns the graph that backs this [`Tree`], consuming it.
+    #[inline]
+    pub fn into_graph(self) -> G {
+        self.graph
+    }
+
+    /// Returns the parent of `vertex`, or `None` if it is the root or not in this [`Tree`].
+    #[inline]
+    pub fn parent(&self, vertex: G::VertexId) -> Option<G::VertexId> {
+        self.graph.vertex_weight(vertex)?;
+        self.graph
+            .neighbors_directed(vertex, Direction::Incoming)
+            .next()
+    }
+
+    /// Returns the edge from the parent of `vertex` to it, or `None` if it is the root or not in
+    /// this [`Tree`].
+    #[inline]
+    pub fn parent_edge(&self, vertex: G::VertexId) -> Option<G::EdgeId> {
+        self.graph.vertex_weight(vertex)?;
+        self.graph
+            .edges_directed(vertex, Direction::Incoming)
+            .next()
+            .map(|(edge, _)| edge)
+    }
+
+    /// Walks the subtree rooted at `vertex` in post-order, visiting every vertex after its
+    /// children.
+    ///
+    /// # Panics:
+    /// Panics if `vertex` is not in this [`Tree`].
+    #[inline]
+    pub fn post_order(&self, vertex: G::VertexId) -> PostOrder<'_, G> {
+        PostOrder {
+            tree: self,
+            stack: vec![(vertex, self.children(vertex))],
+        }
+    }
+
+    /// Walks the subtree rooted at `vertex` in pre-order, visiting every vertex before its
+    /// children.
+    ///
+    /// # Panics:
+    /// Panics if `vertex` is not in this [`Tree`].
+    #[inline]
+    pub fn pre_order(&self, vertex: G::VertexId) -> PreOrder<'_, G> {
+        assert!(
+            self.graph.vertex_weight(vertex).is_some(),
+            "Vertex not in tree."
+        );
+
+        PreOrder {
+            tree: self,
+            stack: vec![vertex],
+        }
+    }
+
+    /// Returns the root of this [`Tree`].
+    #[inline]
+    pub fn root(&self) -> G::VertexId {
+        self.root
+    }
+
+    /// Returns the vertices of the subtree rooted at `vertex`, which are `vertex` and its
+    /// descendants, in pre-order.
+    ///
+    /// # Panics:
+    /// Panics if `vertex` is not in this [`Tree`].
+    #[inline]
+    pub fn subtree(&self, vertex: G::VertexId) -> PreOrder<'_, G> {
+        self.pre_order(vertex)
+    }
+}
+
+impl<V: Clone, E: Clone, Ix: IndexType> UnstableTree<V, E, Ix> {
+    /// Creates a [`Tree`] from `graph` rooted at `root`, keeping the indices of its vertices and
+    /// edges.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `root` is out of bounds, if `root` has a parent or another vertex has
+    /// more than one, or if a vertex cannot be reached from `root`.
+    pub fn from_graph<C: Cyclicness, P: EdgePolicy>(
+        graph: &UnstableGraph<V, E, Directed, C, P, Ix>,
+        root: VertexIndex<Ix>,
+    ) -> Result<Self, GraphError<VertexIndex<Ix>, EdgeIndex<Ix>>> {
+        check_tree(graph, root)?;
+
+        Ok(Tree {
+            graph: graph.retype(),
+            root,
+        })
+    }
+}
+
+impl<V: Clone, E: Clone> StableTree<V, E> {
+    /// Creates a [`Tree`] from `graph` rooted at `root`, keeping the keys of its vertices and
+    /// edges.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `root` is not in `graph`, if `root` has a parent or another vertex has
+    /// more than one, or if a vertex cannot be reached from `root`.
+    pub fn from_graph<C: Cyclicness, P: EdgePolicy>(
+        graph: &StableGraph<V, E, Directed, C, P>,
+        root: VertexKey,
+    ) -> Result<Self, GraphError<VertexKey, EdgeKey>> {
+        check_tree(graph, root)?;
+
+        Ok(Tree {
+            graph: graph.retype(),
+            root,
+        })
+    }
+
+    /// Removes `vertex` along with its descendants and the edges that lead to them, returning
+    /// the weight of `vertex`, or `None` if it is the root or not in this [`Tree`]. The keys of
+    /// all other vertices and edges remain valid.
+    pub fn remove_subtree(&mut self, vertex: VertexKey) -> Option<V> {
+        if vertex == self.root || !self.graph.contains_vertex(vertex) {
+            return None;
+        }
+
+        let descendants = self.pre_order(vertex).skip(1).collect::<Vec<_>>();
+
+        for descendant in descendants {
+            self.graph.remove_vertex(descendant);
+        }
+
+        self.graph.remove_vertex(vertex)
+    }
+}
+
+/// Checks that `graph` is a tree rooted at `root`.
+fn check_tree<G>(graph: &G, root: G::VertexId) -> Result<(), GraphError<G::VertexId, G::EdgeId>>
+where
+    G: Data + VertexIds + Neighbors + Visitable,
+{
+    if graph.vertex_weight(root).is_none() {
+        return Err(GraphError::InvalidVertex(root));
+    }
+
+    for vertex in graph.vertex_ids() {
+        let parents = graph
+            .neighbors_directed(vertex, Direction::Incoming)
+            .count();
+
+        match (vertex == root, parents) {
+            (true, 0) | (false, 1) => {}
+            (false, 0) => return Err(GraphError::Unreachable(vertex)),
+            _ => return Err(GraphError::MultipleParents(vertex)),
+        }
+    }
+
+    // Every other vertex has one parent, so any that the root cannot reach lie on a cycle.
+    let mut reached = graph.visit_map();
+    let mut pending = vec![root];
+    reached.visit(root);
+
+    while let Some(vertex) = pending.pop() {
+        pending.extend(
+            graph
+                .neighbors(vertex)
+                .filter(|&child| reached.visit(child)),
+        );
+    }
+
+    match graph
+        .vertex_ids()
+        .find(|&vertex| !reached.is_visited(vertex))
+    {
+        Some(vertex) => Err(GraphError::Unreachable(vertex)),
+        None => Ok(()),
+    }
+}
+
+/// An iterator over the ancestors of a vertex of a [`Tree`], from its parent up to the root.
+#[derive(Clone, Debug)]
+pub struct Ancestors<'a, G: TreeGraph> {
+    tree: &'a Tree<G>,
+    next: Option<G::VertexId>,
+}
+
+impl<G: TreeGraph> Iterator for Ancestors<'_, G> {
+    type Item = G::VertexId;
+
+    fn next(&mut self) -> Option<G::VertexId> {
+        let vertex = self.next?;
+        self.next = self.tree.parent(vertex);
+
+        Some(vertex)
+    }
+}
+
+/// An iterator that walks a subtree of a [`Tree`] in pre-order.
+#[derive(Clone, Debug)]
+pub struct PreOrder<'a, G: TreeGraph> {
+    tree: &'a Tree<G>,
+    stack: Vec<G::VertexId>,
+}
+
+impl<G: TreeGraph> Iterator for PreOrder<'_, G> {
+    type Item = G::VertexId;
+
+    fn next(&mut self) -> Option<G::VertexId> {
+        let vertex = self.stack.pop()?;
+
+        let start = self.stack.len();
+        self.stack.extend(self.tree.children(vertex));
+        self.stack[start..].reverse();
+
+        Some(vertex)
+    }
+}
+
+/// An iterator that walks a subtree of a [`Tree`] in post-order.
+pub struct PostOrder<'a, G: TreeGraph> {
+    tree: &'a Tree<G>,
+    stack: Vec<(G::VertexId, Children<'a, G>)>,
+}
+
+impl<G: TreeGraph> Iterator for PostOrder<'_, G> {
+    type Item = G::VertexId;
+
+    fn next(&mut self) -> Option<G::VertexId> {
+        loop {
+            let (vertex, children) = self.stack.last_mut()?;
+
+            match children.next() {
+                Some(child) => {
+                    let grandchildren = self.tree.children(child);
+                    self.stack.push((child, grandchildren));
+                }
+                None => {
+                    let vertex = *vertex;
+                    self.stack.pop();
+                    return Some(vertex);
+                }
+            }
+        }
+    }
+}
+
+impl<G: TreeGraph> GraphBase for Tree<G> {
+    type VertexId = G::VertexId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: TreeGraph> GraphProp for Tree<G> {
+    type EdgeType = Directed;
+}
+
+impl<G: TreeGraph> Data for Tree<G> {
+    type VertexWeight = G::VertexWeight;
+    type EdgeWeight = G::EdgeWeight;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: G::VertexId) -> Option<&G::VertexWeight> {
+        self.graph.vertex_weight(vertex)
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: G::EdgeId) -> Option<&G::EdgeWeight> {
+        self.graph.edge_weight(edge)
+    }
+}
+
+impl<G: TreeGraph> Counts for Tree<G> {
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.graph.vertex_count()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+}
+
+impl<G: TreeGraph> VertexIds for Tree<G> {
+    type VertexIds<'a>
+        = G::VertexIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        self.graph.vertex_ids()
+    }
+}
+
+impl<G: TreeGraph> EdgeIds for Tree<G> {
+    type EdgeIds<'a>
+        = G::EdgeIds<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> Self::EdgeIds<'_> {
+        self.graph.edge_ids()
+    }
+}
+
+impl<G: TreeGraph> EdgeEndpoints for Tree<G> {
+    #[inline]
+    fn edge_endpoints(&self, edge: G::EdgeId) -> Option<(G::VertexId, G::VertexId)> {
+        self.graph.edge_endpoints(edge)
+    }
+}
+
+impl<G: TreeGraph> IncidentEdges for Tree<G> {
+    type Edges<'a>
+        = G::Edges<'a>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is not in this [`Tree`].
+    #[inline]
+    fn edges_directed(&self, vertex: G::VertexId, direction: Direction) -> Self::Edges<'_> {
+        self.graph.edges_directed(vertex, direction)
+    }
+}
+
+impl<G: TreeGraph> Neighbors for Tree<G> {
+    type Neighbors<'a>
+        = G::Neighbors<'a>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is not in this [`Tree`].
+    #[inline]
+    fn neighbors_directed(&self, vertex: G::VertexId, direction: Direction) -> Self::Neighbors<'_> {
+        self.graph.neighbors_directed(vertex, direction)
+    }
+}
+
+impl<G: TreeGraph> Visitable for Tree<G> {
+    type Map = G::Map;
+
+    #[inline]
+    fn visit_map(&self) -> G::Map {
+        self.graph.visit_map()
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut G::Map) {
+        self.graph.reset_map(map);
+    }
+}
+
+impl<V: Clone, E: Clone, Ix: IndexType> Index<VertexIndex<Ix>> for UnstableTree<V, E, Ix> {
+    type Output = V;
+
+    /// Returns the weight of the vertex at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: VertexIndex<Ix>) -> &V {
+        &self.graph[index]
+    }
+}
+
+impl<V: Clone, E: Clone, Ix: IndexType> IndexMut<VertexIndex<Ix>> for UnstableTree<V, E, Ix> {
+    /// Returns the weight of the vertex at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: VertexIndex<Ix>) -> &mut V {
+        &mut self.graph[index]
+    }
+}
+
+impl<V: Clone, E: Clone, Ix: IndexType> Index<EdgeIndex<Ix>> for UnstableTree<V, E, Ix> {
+    type Output = E;
+
+    /// Returns the weight of the edge at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.graph[index]
+    }
+}
+
+impl<V: Clone, E: Clone, Ix: IndexType> IndexMut<EdgeIndex<Ix>> for UnstableTree<V, E, Ix> {
+    /// Returns the weight of the edge at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: EdgeIndex<Ix>) -> &mut E {
+        &mut self.graph[index]
+    }
+}
+
+impl<V: Clone, E: Clone> Index<VertexKey> for StableTree<V, E> {
+    type Output = V;
+
+    /// Returns the weight of the vertex at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to a vertex in this [`Tree`].
+    #[inline]
+    fn index(&self, key: VertexKey) -> &V {
+        &self.graph[key]
+    }
+}
+
+impl<V: Clone, E: Clone> IndexMut<VertexKey> for StableTree<V, E> {
+    /// Returns the weight of the vertex at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to a vertex in this [`Tree`].
+    #[inline]
+    fn index_mut(&mut self, key: VertexKey) -> &mut V {
+        &mut self.graph[key]
+    }
+}
+
+impl<V: Clone, E: Clone> Index<EdgeKey> for StableTree<V, E> {
+    type Output = E;
+
+    /// Returns the weight of the edge at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to an edge in this [`Tree`].
+    #[inline]
+    fn index(&self, key: EdgeKey) -> &E {
+        &self.graph[key]
+    }
+}
+
+impl<V: Clone, E: Clone> IndexMut<EdgeKey> for StableTree<V, E> {
+    /// Returns the weight of the edge at `key`.
+    ///
+    /// # Panics:
+    /// Panics if `key` does not refer to an edge in this [`Tree`].
+    #[inline]
+    fn index_mut(&mut self, key: EdgeKey) -> &mut E {
+        &mut self.graph[key]
+    }
+}
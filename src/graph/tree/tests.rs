@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use super::*;
+use crate::graph::{Cyclic, Pseudo};
+
+/// Builds the tree `a(b(d, e), c(f))`.
+fn sample() -> (UnstableTree<char, ()>, [VertexIndex; 6]) {
+    let mut tree = UnstableTree::new('a');
+    let a = tree.root();
+    let b = tree.insert_child(a, 'b', ()).unwrap();
+    let c = tree.insert_child(a, 'c', ()).unwrap();
+    let d = tree.insert_child(b, 'd', ()).unwrap();
+    let e = tree.insert_child(b, 'e', ()).unwrap();
+    let f = tree.insert_child(c, 'f', ()).unwrap();
+
+    (tree, [a, b, c, d, e, f])
+}
+
+#[test]
+fn structure() {
+    let (tree, [a, b, c, d, e, f]) = sample();
+
+    assert_eq!(tree.parent(a), None);
+    assert_eq!(tree.parent(e), Some(b));
+    assert_eq!(tree.children(a).collect::<Vec<_>>(), [b, c]);
+    assert_eq!(tree.depth(a), Some(0));
+    assert_eq!(tree.depth(f), Some(2));
+    assert_eq!(tree.ancestors(d).collect::<Vec<_>>(), [b, a]);
+    assert_eq!(
+        tree.edge_endpoints(tree.parent_edge(f).unwrap()),
+        Some((c, f))
+    );
+
+    let walk = |order: &mut dyn Iterator<Item = VertexIndex>| {
+        order.map(|vertex| tree[vertex]).collect::<String>()
+    };
+    assert_eq!(walk(&mut tree.pre_order(a)), "abdecf");
+    assert_eq!(walk(&mut tree.post_order(a)), "debfca");
+    assert_eq!(walk(&mut tree.subtree(b)), "bde");
+
+    let missing = VertexIndex::new(6);
+    let mut tree = tree;
+    assert_eq!(
+        tree.insert_child(missing, 'g', ()),
+        Err(GraphError::InvalidVertex(missing))
+    );
+}
+
+#[test]
+fn from_graph() {
+    let mut graph = UnstableGraph::<u8, (), Directed, Cyclic, Pseudo>::directed();
+    let [a, b, c, d] = [0, 1, 2, 3].map(|weight| graph.insert_vertex(weight).unwrap());
+    graph.insert_edge(b, a, ()).unwrap();
+    graph.insert_edge(b, c, ()).unwrap();
+
+    assert_eq!(
+        UnstableTree::from_graph(&graph, b).err(),
+        Some(GraphError::Unreachable(d))
+    );
+
+    graph.insert_edge(c, d, ()).unwrap();
+    let tree = UnstableTree::from_graph(&graph, b).unwrap();
+    assert_eq!(tree.root(), b);
+    assert_eq!(tree.ancestors(d).collect::<Vec<_>>(), [c, b]);
+    assert_eq!(tree[d], 3);
+
+    assert_eq!(
+        UnstableTree::from_graph(&graph, a).err(),
+        Some(GraphError::MultipleParents(a))
+    );
+
+    // `d` keeps its single parent, but `c` and `d` form a cycle that `b` cannot reach.
+    let mut cycle = graph.clone();
+    let bc = cycle
+        .edges_directed(b, Direction::Outgoing)
+        .nth(1)
+        .unwrap()
+        .0;
+    cycle.remove_edge(bc);
+    cycle.insert_edge(d, c, ()).unwrap();
+    assert_eq!(
+        UnstableTree::from_graph(&cycle, b).err(),
+        Some(GraphError::Unreachable(c))
+    );
+
+    graph.insert_edge(a, d, ()).unwrap();
+    assert_eq!(
+        UnstableTree::from_graph(&graph, b).err(),
+        Some(GraphError::MultipleParents(d))
+    );
+}
+
+#[test]
+fn stable() {
+    let mut graph = StableGraph::<char, (), Directed, Cyclic, Pseudo>::new_directed();
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|weight| graph.insert_vertex(weight));
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(a, c, ()).unwrap();
+    graph.insert_edge(b, d, ()).unwrap();
+
+    let mut tree = StableTree::from_graph(&graph, a).unwrap();
+    assert_eq!(tree.ancestors(d).collect::<Vec<_>>(), [b, a]);
+    assert_eq!(
+        StableTree::from_graph(&graph, b).err(),
+        Some(GraphError::Unreachable(a))
+    );
+
+    let e = tree.insert_child(d, 'e', ()).unwrap();
+    let f = tree.insert_child(c, 'f', ()).unwrap();
+    let walk = |tree: &StableTree<char, ()>| {
+        tree.pre_order(tree.root())
+            .map(|vertex| tree[vertex])
+            .collect::<String>()
+    };
+    assert_eq!(walk(&tree), "abdecf");
+
+    assert_eq!(tree.remove_subtree(a), None);
+    assert_eq!(tree.remove_subtree(b), Some('b'));
+    assert_eq!(tree.remove_subtree(d), None);
+    assert_eq!(tree.parent(e), None);
+    assert_eq!(tree.depth(e), None);
+    assert_eq!((tree.vertex_count(), tree.edge_count()), (3, 2));
+    assert_eq!(tree.parent(f), Some(c));
+    assert_eq!(walk(&tree), "acf");
+    assert_eq!(
+        tree.insert_child(b, 'g', ()),
+        Err(GraphError::InvalidVertex(b))
+    );
+}
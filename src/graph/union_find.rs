@@ -0,0 +1,54 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A disjoint-set forest over vertex handles, in which a vertex that has not been seen yet is in
+/// a set of its own.
+#[derive(Clone, Debug)]
+pub(crate) struct UnionFind<N: Copy + Eq + Hash> {
+    parents: HashMap<N, N>,
+    ranks: HashMap<N, u8>,
+}
+
+impl<N: Copy + Eq + Hash> UnionFind<N> {
+    #[inline]
+    pub fn new() -> Self {
+        UnionFind {
+            parents: HashMap::new(),
+            ranks: HashMap::new(),
+        }
+    }
+
+    /// Returns the representative of the set that contains `vertex`.
+    pub fn find(&mut self, mut vertex: N) -> N {
+        while let Some(&parent) = self.parents.get(&vertex) {
+            let grandparent = self.parents.get(&parent).copied().unwrap_or(parent);
+            self.parents.insert(vertex, grandparent);
+            vertex = parent;
+        }
+
+        vertex
+    }
+
+    /// Merges the sets that contain `a` and `b`. Returns `false` if they were already the same
+    /// set.
+    pub fn union(&mut self, a: N, b: N) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (rank_a, rank_b) = (self.rank(a), self.rank(b));
+        let (child, root) = if rank_a < rank_b { (a, b) } else { (b, a) };
+
+        self.parents.insert(child, root);
+        if rank_a == rank_b {
+            self.ranks.insert(root, rank_a + 1);
+        }
+
+        true
+    }
+
+    #[inline]
+    fn rank(&self, vertex: N) -> u8 {
+        self.ranks.get(&vertex).copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::graph::traits::VisitMap;
+use std::{fmt::Debug, hash::Hash};
+
+/// The integer type that an [`UnstableGraph`][`super::UnstableGraph`] stores its indices as.
+/// Narrower types make a graph smaller at the cost of the number of vertices and edges it can
+/// hold.
+pub trait IndexType: Copy + Default + Ord + Hash + Debug + 'static {
+    /// The largest index that this type can represent.
+    const MAX: usize;
+
+    /// Converts `index` to this type.
+    ///
+    /// # Panics:
+    /// Panics if `index` is greater than [`MAX`][`Self::MAX`].
+    fn new(index: usize) -> Self;
+
+    /// Converts this index to a `usize`.
+    fn index(self) -> usize;
+}
+
+macro_rules! impl_index_type {
+    ($($ty:ty),*) => {
+        $(
+            impl IndexType for $ty {
+                const MAX: usize = <$ty>::MAX as usize;
+
+                #[inline]
+                fn new(index: usize) -> Self {
+                    <$ty>::try_from(index).expect("Index out of range for the graph's index type.")
+                }
+
+                #[inline]
+                fn index(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_index_type!(u16, u32, usize);
+
+/// The index of a [`Vertex`][`super::Vertex`] in an [`UnstableGraph`][`super::UnstableGraph`],
+/// or of a vertex in a [`CsrGraph`][`crate::graph::csr_graph::CsrGraph`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VertexIndex<Ix: IndexType = usize>(Ix);
+
+/// The index of an [`Edge`][`super::Edge`] in an [`UnstableGraph`][`super::UnstableGraph`], or
+/// of an edge in a [`CsrGraph`][`crate::graph::csr_graph::CsrGraph`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeIndex<Ix: IndexType = usize>(Ix);
+
+impl<Ix: IndexType> VertexIndex<Ix> {
+    /// Creates a [`VertexIndex`] that refers to the `index`th vertex of a graph.
+    ///
+    /// # Panics:
+    /// Panics if `index` is greater than [`Ix::MAX`][`IndexType::MAX`].
+    #[inline]
+    pub fn new(index: usize) -> Self {
+        VertexIndex(Ix::new(index))
+    }
+
+    /// Returns the position of the vertex in its graph's `vertices`.
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0.index()
+    }
+}
+
+impl<Ix: IndexType> EdgeIndex<Ix> {
+    /// Creates an [`EdgeIndex`] that refers to the `index`th edge of a graph.
+    ///
+    /// # Panics:
+    /// Panics if `index` is greater than [`Ix::MAX`][`IndexType::MAX`].
+    #[inline]
+    pub fn new(index: usize) -> Self {
+        EdgeIndex(Ix::new(index))
+    }
+
+    /// Returns the position of the edge in its graph's `edges`.
+    #[inline]
+    pub fn index(self) -> usize {
+        self.0.index()
+    }
+}
+
+impl<Ix: IndexType> VisitMap<VertexIndex<Ix>> for Vec<bool> {
+    #[inline]
+    fn visit(&mut self, vertex: VertexIndex<Ix>) -> bool {
+        self.visit(vertex.index())
+    }
+
+    #[inline]
+    fn is_visited(&self, vertex: VertexIndex<Ix>) -> bool {
+        self.is_visited(vertex.index())
+    }
+}
@@ -1,111 +1,79 @@
-mod dft;
+mod index;
 mod tests;
 
 use super::*;
-use dft::*;
-use std::marker::PhantomData;
-
-/// Uninhabited type that indicates that an [`UnstableGraph`] is cyclic.
-#[derive(Clone, Debug)]
-pub enum Cyclic {}
-
-/// Uninhabited type that indicates that [`UnstableGraph`] is acyclic.
-#[derive(Clone, Debug)]
-pub enum Acyclic {}
-
-/// A trait for [`UnstableGraph`]'s cyclicness types.
-pub trait Cyclicness: Sized {
-    /// The edge insertion behaviour for cyclic or acyclic [`UnstableGraph]`s.
-    ///
-    /// # Fallible:
-    /// Returns an `Err` if the [`UnstableGraph`] being inserted to is acyclic and the insertion
-    /// of this [`Edge`] would introduce a cycle.
-    fn insert_edge<V: Clone, E: Clone, D: EdgeType>(
-        graph: &mut UnstableGraph<V, E, D, Self>,
-        edge: Edge<E>,
-    ) -> Result<usize, CycleError>;
-
-    /// Returns `true` if the type is cyclic.
-    fn is_cyclic() -> bool;
-}
-
-impl Cyclicness for Cyclic {
-    /// The edge insertion behaviour for cyclic [`UnstableGraph`]s.
-    fn insert_edge<V: Clone, E: Clone, D: EdgeType>(
-        graph: &mut UnstableGraph<V, E, D, Self>,
-        edge: Edge<E>,
-    ) -> Result<usize, CycleError> {
-        Ok(graph.insert_edge_unchecked(edge))
-    }
-
-    /// Returns `true` if the type is cyclic. Always returns `true`.
-    fn is_cyclic() -> bool {
-        true
-    }
-}
-
-impl Cyclicness for Acyclic {
-    /// The edge insertion behaviour for cyclic [`UnstableGraph`]s.
-    fn insert_edge<V: Clone, E: Clone, D: EdgeType>(
-        graph: &mut UnstableGraph<V, E, D, Self>,
-        edge: Edge<E>,
-    ) -> Result<usize, CycleError> {
-        if graph.would_cycle_with_edge(edge.clone()) {
-            return Err(CycleError);
-        }
-        Ok(graph.insert_edge_unchecked(edge))
-    }
-
-    /// Returns `true` if the type is cyclic. Always returns `false`.
-    fn is_cyclic() -> bool {
-        false
-    }
-}
+pub use index::{EdgeIndex, IndexType, VertexIndex};
+use std::{
+    iter::Map,
+    marker::PhantomData,
+    ops::{Index, IndexMut, Range},
+};
+use traits::{
+    Counts, Data, EdgeEndpoints, EdgeIds, GraphBase, GraphProp, IncidentEdges, Neighbors,
+    VertexIds, Visitable,
+};
+use Direction::{Incoming, Outgoing};
 
 /// The vertex type for [`UnstableGraph`].
 #[derive(Clone, Debug)]
-pub struct Vertex<V: Clone> {
+pub struct Vertex<V: Clone, Ix: IndexType = usize> {
     pub weight: V,
-    edge_indices: Vec<usize>,
+    edge_indices: [Vec<EdgeIndex<Ix>>; 2],
 }
 
 /// The edge type for [`UnstableGraph`].
 #[derive(Clone, Debug)]
-pub struct Edge<E: Clone> {
+pub struct Edge<E: Clone, Ix: IndexType = usize> {
     pub weight: E,
-    vertex_indices: [usize; 2],
+    vertex_indices: [VertexIndex<Ix>; 2],
 }
 
-impl<V: Clone> Vertex<V> {
-    fn associate_edge(&mut self, index: usize) {
-        match self.edge_indices.iter().find(|&&idx| idx == index) {
-            Some(_) => return,
-            None => self.edge_indices.push(index),
+impl<V: Clone, Ix: IndexType> Vertex<V, Ix> {
+    fn associate_edge(&mut self, index: EdgeIndex<Ix>, direction: Direction) {
+        let edge_indices = &mut self.edge_indices[direction as usize];
+
+        if !edge_indices.contains(&index) {
+            edge_indices.push(index);
         }
     }
 
-    fn dissociate_edge(&mut self, index: usize) {
-        self.edge_indices.retain(|&idx| idx != index);
+    fn dissociate_edge(&mut self, index: EdgeIndex<Ix>) {
+        for edge_indices in &mut self.edge_indices {
+            edge_indices.retain(|&idx| idx != index);
+        }
     }
 
-    /// Gets the indices of the [`Edge`]s that are connected to this [`Vertex`].
+    fn rename_edge(&mut self, from: EdgeIndex<Ix>, to: EdgeIndex<Ix>) {
+        for idx in self.edge_indices.iter_mut().flatten() {
+            if *idx == from {
+                *idx = to;
+            }
+        }
+    }
+
+    /// Gets the indices of the [`Edge`]s that leave or enter this [`Vertex`], depending on
+    /// `direction`. An undirected edge leaves the [`Vertex`] it was inserted with as its source.
     #[inline]
-    pub fn edge_indices(&self) -> &[usize] {
-        &self.edge_indices
+    pub fn edge_indices(&self, direction: Direction) -> &[EdgeIndex<Ix>] {
+        &self.edge_indices[direction as usize]
     }
 
     #[inline]
-    fn new(weight: V) -> Vertex<V> {
+    fn new(weight: V) -> Vertex<V, Ix> {
         Vertex {
             weight,
-            edge_indices: vec![],
+            edge_indices: [vec![], vec![]],
         }
     }
 }
 
-impl<E: Clone> Edge<E> {
+impl<E: Clone, Ix: IndexType> Edge<E, Ix> {
     #[inline]
-    fn new(source: usize, destination: usize, weight: E) -> Edge<E> {
+    pub(crate) fn new(
+        source: VertexIndex<Ix>,
+        destination: VertexIndex<Ix>,
+        weight: E,
+    ) -> Edge<E, Ix> {
         Edge {
             weight,
             vertex_indices: [source, destination],
@@ -113,23 +81,60 @@ impl<E: Clone> Edge<E> {
     }
 
     /// Gets the indices of the [`Vertices`] that are connected to this [`Edge`].
-    pub fn vertex_indices(&self) -> (usize, usize) {
+    pub fn vertex_indices(&self) -> (VertexIndex<Ix>, VertexIndex<Ix>) {
         (self.vertex_indices[0], self.vertex_indices[1])
     }
 }
 
+/// A [`Vertex`] or [`Edge`] weight that was removed from an [`UnstableGraph`], where `I` is the
+/// index type of the removed item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Removed<T, I> {
+    /// The weight of the removed item.
+    pub weight: T,
+    /// The former index of the item that was moved into the vacated index to fill it, or `None`
+    /// if the removed item was the last one and no item was moved.
+    pub moved: Option<I>,
+}
+
 #[derive(Clone, Debug)]
-pub struct UnstableGraph<V: Clone, E: Clone, D: EdgeType, C: Cyclicness> {
+pub struct UnstableGraph<
+    V: Clone,
+    E: Clone,
+    D: EdgeType,
+    C: Cyclicness,
+    P: EdgePolicy = Pseudo,
+    Ix: IndexType = usize,
+> {
     _cyclic: PhantomData<C>,
     _directed: PhantomData<D>,
-    pub edges: Vec<Edge<E>>,
-    pub vertices: Vec<Vertex<V>>,
+    _policy: PhantomData<P>,
+    components: Components<VertexIndex<Ix>>,
+    pub edges: Vec<Edge<E, Ix>>,
+    pub vertices: Vec<Vertex<V, Ix>>,
 }
 
-impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness> UnstableGraph<V, E, D, C> {
-    /// Returns `true` if this [`UnstableGraph`] contains a cycle.
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType>
+    UnstableGraph<V, E, D, C, P, Ix>
+{
+    /// Returns `true` if this [`UnstableGraph`] contains a cycle. In an undirected graph, a
+    /// self-loop or a pair of parallel edges is a cycle.
     pub fn cycles(&self) -> bool {
-        Dft::from(self).cycles()
+        has_cycle(self)
+    }
+
+    /// Creates an empty [`UnstableGraph`] of any edge type and cyclicness with a preallocated
+    /// capacity for `vertex_cap` vertices and `edge_cap` edges, for conversions from graphs that
+    /// already uphold its invariants.
+    pub(crate) fn with_capacities(vertex_cap: usize, edge_cap: usize) -> Self {
+        UnstableGraph {
+            vertices: Vec::with_capacity(vertex_cap),
+            edges: Vec::with_capacity(edge_cap),
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
     }
 
     /// Returns the number of edges in this [`UnstableGraph`].
@@ -138,129 +143,302 @@ impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness> UnstableGraph<V, E, D, C> {
         self.edges.len()
     }
 
-    /// Inserts an [`Edge`] into this [`UnstableGraph`].
+    /// Returns `true` if `index` refers to a [`Vertex`] in this [`UnstableGraph`].
     #[inline]
+    pub fn contains_vertex(&self, index: VertexIndex<Ix>) -> bool {
+        index.index() < self.vertices.len()
+    }
+
+    /// Returns the subgraph that consists of the edges at `edges` and their endpoints, along with
+    /// the map between its indices and those of this [`UnstableGraph`]. The subgraph's indices are
+    /// compacted, but its vertices and edges keep their relative order.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if any index in `edges` is out of bounds.
+    pub fn edge_subgraph<I: IntoIterator<Item = EdgeIndex<Ix>>>(
+        &self,
+        edges: I,
+    ) -> Result<Subgraph<Self>, GraphError<VertexIndex<Ix>, EdgeIndex<Ix>>> {
+        let mut kept_vertices = vec![false; self.vertices.len()];
+        let mut kept_edges = vec![false; self.edges.len()];
+
+        for index in edges {
+            let edge = self
+                .edges
+                .get(index.index())
+                .ok_or(GraphError::InvalidEdge(index))?;
+
+            kept_edges[index.index()] = true;
+            for vertex in edge.vertex_indices {
+                kept_vertices[vertex.index()] = true;
+            }
+        }
+
+        Ok(self.subgraph(&kept_vertices, |index| kept_edges[index.index()]))
+    }
+
+    /// Returns the subgraph that consists of the vertices at `vertices` and every edge between
+    /// them, along with the map between its indices and those of this [`UnstableGraph`]. The
+    /// subgraph's indices are compacted, but its vertices and edges keep their relative order.
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if any index in `vertices` is out of bounds.
+    pub fn induced_subgraph<I: IntoIterator<Item = VertexIndex<Ix>>>(
+        &self,
+        vertices: I,
+    ) -> Result<Subgraph<Self>, GraphError<VertexIndex<Ix>, EdgeIndex<Ix>>> {
+        let mut kept_vertices = vec![false; self.vertices.len()];
+
+        for index in vertices {
+            if !self.contains_vertex(index) {
+                return Err(GraphError::InvalidVertex(index));
+            }
+
+            kept_vertices[index.index()] = true;
+        }
+
+        Ok(self.subgraph(&kept_vertices, |_| true))
+    }
+
+    /// Copies the vertices that are marked in `kept_vertices` and the edges between them that
+    /// `keep_edge` accepts into a new [`UnstableGraph`].
+    fn subgraph<F: Fn(EdgeIndex<Ix>) -> bool>(
+        &self,
+        kept_vertices: &[bool],
+        keep_edge: F,
+    ) -> Subgraph<Self> {
+        let vertex_count = kept_vertices.iter().filter(|&&kept| kept).count();
+        let mut subgraph = Self::with_capacities(vertex_count, 0);
+        let mut map = SubgraphMap::new();
+
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            if kept_vertices[index] {
+                let new = subgraph.insert_vertex_unchecked(vertex.weight.clone());
+                map.insert_vertex(VertexIndex::new(index), new);
+            }
+        }
+
+        for (index, edge) in self.edges.iter().enumerate() {
+            let index = EdgeIndex::new(index);
+            let [source, target] = edge.vertex_indices;
+
+            if let (Some(source), Some(target)) = (map.vertex(source), map.vertex(target)) {
+                if keep_edge(index) {
+                    let new = subgraph.insert_edge_unchecked(Edge::new(
+                        source,
+                        target,
+                        edge.weight.clone(),
+                    ));
+                    map.insert_edge(index, new);
+                }
+            }
+        }
+
+        (subgraph, map)
+    }
+
+    /// Inserts an [`Edge`] into this [`UnstableGraph`].
+    ///
+    /// # Fallible:
+    /// Returns an `Err` if `source` or `destination` is out of bounds, if `Ix` cannot index
+    /// another [`Edge`], if the [`Edge`] would be a self-loop or parallel edge that `P` does not
+    /// allow, or if this [`UnstableGraph`] is acyclic and the [`Edge`] would introduce a cycle.
     pub fn insert_edge(
         &mut self,
-        source: usize,
-        destination: usize,
+        source: VertexIndex<Ix>,
+        destination: VertexIndex<Ix>,
         weight: E,
-    ) -> Result<usize, CycleError> {
-        C::insert_edge(self, Edge::new(source, destination, weight))
-    }
+    ) -> Result<EdgeIndex<Ix>, GraphError<VertexIndex<Ix>, EdgeIndex<Ix>>> {
+        for vertex in [source, destination] {
+            if !self.contains_vertex(vertex) {
+                return Err(GraphError::InvalidVertex(vertex));
+            }
+        }
 
-    fn insert_edge_unchecked(&mut self, edge: Edge<E>) -> usize {
-        let index = self.edges.len();
-        let (from, to) = edge.vertex_indices();
+        if self.edges.len() > Ix::MAX {
+            return Err(GraphError::GraphFull);
+        }
 
-        match self.vertices.get_mut(from) {
-            Some(source_vertex) => {
-                source_vertex.associate_edge(index);
-            }
+        check_policy::<_, P, _>(self, source, destination)?;
 
-            _ => panic!("Invalid index in edge upon insertion into graph."),
-        };
+        if !C::is_cyclic() {
+            let mut components = self.components.take();
+            let cycle = would_cycle(self, &mut components, source, destination);
+            self.components = components;
 
-        match self.vertices.get_mut(to) {
-            Some(target_vertex) => {
-                target_vertex.associate_edge(index);
+            if let Some(cycle) = cycle {
+                return Err(GraphError::WouldCycle(cycle));
             }
+        }
 
-            _ => panic!("Invalid index in edge upon insertion into graph."),
-        };
+        Ok(self.insert_edge_unchecked(Edge::new(source, destination, weight)))
+    }
+
+    /// Inserts `edge` without checking it; its endpoints must be in this [`UnstableGraph`].
+    pub(crate) fn insert_edge_unchecked(&mut self, edge: Edge<E, Ix>) -> EdgeIndex<Ix> {
+        let index = EdgeIndex::new(self.edges.len());
+        let (from, to) = edge.vertex_indices();
+
+        if let Some(components) = &mut self.components {
+            components.union(from, to);
+        }
 
+        self.vertices[from.index()].associate_edge(index, Direction::Outgoing);
+        self.vertices[to.index()].associate_edge(index, Direction::Incoming);
         self.edges.push(edge);
 
         index
     }
 
-    /// Inserts a [`Vertex`] into this [`UnstableGraph`].
-    #[inline]
-    pub fn insert_vertex(&mut self, weight: V) -> usize {
+    /// Inserts `weight` as a [`Vertex`] without checking that `Ix` can index it.
+    pub(crate) fn insert_vertex_unchecked(&mut self, weight: V) -> VertexIndex<Ix> {
         self.vertices.push(Vertex::new(weight));
-        self.vertices.len() - 1
+        VertexIndex::new(self.vertices.len() - 1)
     }
 
-    /// Removes the [`Edge`] at `index` from this [`UnstableGraph`] while associating edges with
-    /// and dissociating edges from [`Vertex`]es as necessary. This method incurs greater overhead
-    /// relative to its counterpart [`remove_edge_simple`][`Self::remove_edge_simple()`], but is needed
-    /// to prevent the loss of an [`UnstableGraph`]'s meaning as a result of modifying its shape.
-    #[inline]
-    pub fn remove_edge(&mut self, index: usize) {
-        let top_index = self.edges.len() - 1;
-        let top = &self.edges[top_index];
-
-        for idx in top.vertex_indices {
-            let vertex = &mut self.vertices[idx];
-            vertex.dissociate_edge(top_index);
-            vertex.associate_edge(index)
+    /// Inserts a [`Vertex`] into this [`UnstableGraph`].
+    ///
+    /// # Fallible:
+    /// Returns [`GraphError::GraphFull`] if `Ix` cannot index another [`Vertex`].
+    pub fn insert_vertex(
+        &mut self,
+        weight: V,
+    ) -> Result<VertexIndex<Ix>, GraphError<VertexIndex<Ix>, EdgeIndex<Ix>>> {
+        if self.vertices.len() > Ix::MAX {
+            return Err(GraphError::GraphFull);
         }
 
-        self.remove_edge_simple(index);
+        Ok(self.insert_vertex_unchecked(weight))
     }
 
-    /// Removes the [`Edge`] at `index` from this [`UnstableGraph`]. Note that this is may
-    /// lead to unintended consequences as the [`Vertex`]es that are associated with the [`Edge`]
-    /// at `index` may become associated with  another [`Edge`] at the same index. To avoid
-    /// this, use [`remove_edge`][`Self::remove_edge()`].
-    #[inline]
-    pub fn remove_edge_simple(&mut self, index: usize) {
-        self.edges.swap_remove(index);
+    /// Copies this [`UnstableGraph`] into one with another cyclicness and edge policy, keeping
+    /// every index. The caller must have checked that the graph upholds them.
+    pub(crate) fn retype<C2: Cyclicness, P2: EdgePolicy>(
+        &self,
+    ) -> UnstableGraph<V, E, D, C2, P2, Ix> {
+        UnstableGraph {
+            vertices: self.vertices.clone(),
+            edges: self.edges.clone(),
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
+    }
+
+    /// Removes the [`Edge`] at `index` from this [`UnstableGraph`] while associating edges with
+    /// and dissociating edges from [`Vertex`]es as necessary. The last [`Edge`] is moved into
+    /// `index` to fill it, and its index is reported in the returned [`Removed`].
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn remove_edge(&mut self, index: EdgeIndex<Ix>) -> Option<Removed<E, EdgeIndex<Ix>>> {
+        let [source, target] = self.edges.get(index.index())?.vertex_indices;
+        self.components = None;
+        self.vertices[source.index()].dissociate_edge(index);
+        self.vertices[target.index()].dissociate_edge(index);
+
+        let top_index = EdgeIndex::new(self.edges.len() - 1);
+        let edge = self.edges.swap_remove(index.index());
+
+        if index == top_index {
+            return Some(Removed {
+                weight: edge.weight,
+                moved: None,
+            });
+        }
+
+        for idx in self.edges[index.index()].vertex_indices {
+            self.vertices[idx.index()].rename_edge(top_index, index);
+        }
+
+        Some(Removed {
+            weight: edge.weight,
+            moved: Some(top_index),
+        })
     }
 
     /// Removes the [`Vertex`] at `index` from this [`UnstableGraph`] while reconfiguring and
     /// removing [`Edge`]s as necessary. Any edges that were connected to the [`Vertex`] at `index`
-    /// will be removed.  This method incurs considerable overhead relative to its counterpart
-    /// [`remove_vertex_simple`][`Self::remove_vertex_simple()`], but is needed to prevent the
-    /// loss of an [`UnstableGraph`]'s meaning as a result of modifying its shape.
-    #[inline]
-    pub fn remove_vertex(&mut self, index: usize) {
-        let top_index = self.vertices.len() - 1;
-        let top = self.vertices[top_index].clone();
-        let target = self.vertices[index].clone();
+    /// will be removed, which may move other [`Edge`]s as described in
+    /// [`remove_edge`][`Self::remove_edge()`]. The last [`Vertex`] is moved into `index` to fill
+    /// it, and its index is reported in the returned [`Removed`].
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn remove_vertex(&mut self, index: VertexIndex<Ix>) -> Option<Removed<V, VertexIndex<Ix>>> {
+        while let Some(&edge) = self
+            .vertices
+            .get(index.index())?
+            .edge_indices
+            .iter()
+            .flatten()
+            .next()
+        {
+            self.remove_edge(edge);
+        }
 
-        for idx in target.edge_indices {
-            self.remove_edge(idx);
+        self.components = None;
+        let top_index = VertexIndex::new(self.vertices.len() - 1);
+        let vertex = self.vertices.swap_remove(index.index());
+
+        if index == top_index {
+            return Some(Removed {
+                weight: vertex.weight,
+                moved: None,
+            });
         }
 
-        for idx in top.edge_indices {
-            self.edges[idx].vertex_indices = self.edges[idx]
-                .vertex_indices
-                .iter()
-                .map(|&ix| if ix == top_index { index } else { ix })
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap()
+        for &edge in self.vertices[index.index()].edge_indices.iter().flatten() {
+            for idx in &mut self.edges[edge.index()].vertex_indices {
+                if *idx == top_index {
+                    *idx = index;
+                }
+            }
         }
 
-        self.remove_edge_simple(index);
+        Some(Removed {
+            weight: vertex.weight,
+            moved: Some(top_index),
+        })
     }
 
-    /// Removes the [`Vertex`] at `index` from this [`UnstableGraph`]. Note that this is may lead to
-    /// unintended consequences as the [`Edge`]s that point to the [`Vertex`] at `index`
-    /// may point to another [`Vertex`] at the same index. To avoid this, use [`remove_vertex`][`Self::remove_vertex()`].
+    /// Returns the number of edges that enter the [`Vertex`] at `index`. For undirected graphs,
+    /// this is the number of edges that are incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
     #[inline]
-    pub fn remove_vertex_simple(&mut self, index: usize) {
-        self.vertices.swap_remove(index);
+    pub fn in_degree(&self, index: VertexIndex<Ix>) -> usize {
+        self.degree(index, Direction::Incoming)
     }
 
-    /// Returns the number of vertices in this [`UnstableGraph`].
+    /// Returns the number of edges that leave the [`Vertex`] at `index`. For undirected graphs,
+    /// this is the number of edges that are incident to it, counting a self-loop once.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
     #[inline]
-    pub fn vertex_count(&self) -> usize {
-        self.vertices.len()
+    pub fn out_degree(&self, index: VertexIndex<Ix>) -> usize {
+        self.degree(index, Direction::Outgoing)
     }
 
-    fn would_cycle_with_edge(&mut self, edge: Edge<E>) -> bool {
-        self.edges.push(edge.clone());
-
-        let cycles = self.cycles();
-        self.edges.pop();
+    fn degree(&self, index: VertexIndex<Ix>, direction: Direction) -> usize {
+        if D::is_directed() {
+            self.vertices[index.index()].edge_indices(direction).len()
+        } else {
+            self.edges_directed(index, direction).count()
+        }
+    }
 
-        cycles
+    /// Returns the number of vertices in this [`UnstableGraph`].
+    #[inline]
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
     }
 }
 
-impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Cyclic> {
+impl<V: Clone, E: Clone, P: EdgePolicy, Ix: IndexType>
+    UnstableGraph<V, E, Directed, Cyclic, P, Ix>
+{
     /// Creates a new directed cyclic [`UnstableGraph`].
     #[inline]
     pub fn directed() -> Self {
@@ -269,6 +447,8 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Cyclic> {
             edges: vec![],
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
@@ -281,6 +461,8 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Cyclic> {
             edges: Vec::with_capacity(cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
@@ -297,11 +479,15 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Cyclic> {
             edges: Vec::with_capacity(edge_cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 }
 
-impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Acyclic> {
+impl<V: Clone, E: Clone, P: EdgePolicy, Ix: IndexType>
+    UnstableGraph<V, E, Directed, Acyclic, P, Ix>
+{
     /// Creates a new directed acyclic [`UnstableGraph`].
     #[inline]
     pub fn directed() -> Self {
@@ -310,6 +496,8 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Acyclic> {
             edges: vec![],
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
@@ -322,6 +510,8 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Acyclic> {
             edges: Vec::with_capacity(cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
@@ -338,11 +528,15 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Directed, Acyclic> {
             edges: Vec::with_capacity(edge_cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 }
 
-impl<V: Clone, E: Clone> UnstableGraph<V, E, Undirected, Cyclic> {
+impl<V: Clone, E: Clone, P: EdgePolicy, Ix: IndexType>
+    UnstableGraph<V, E, Undirected, Cyclic, P, Ix>
+{
     /// Creates a new undirected cyclic [`UnstableGraph`].
     #[inline]
     pub fn undirected() -> Self {
@@ -351,6 +545,8 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Undirected, Cyclic> {
             edges: vec![],
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
@@ -363,6 +559,8 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Undirected, Cyclic> {
             edges: Vec::with_capacity(cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 
@@ -379,6 +577,293 @@ impl<V: Clone, E: Clone> UnstableGraph<V, E, Undirected, Cyclic> {
             edges: Vec::with_capacity(edge_cap),
             _directed: PhantomData,
             _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
         }
     }
 }
+
+impl<V: Clone, E: Clone, P: EdgePolicy, Ix: IndexType>
+    UnstableGraph<V, E, Undirected, Acyclic, P, Ix>
+{
+    /// Creates a new undirected acyclic [`UnstableGraph`], which is a forest: an edge between two
+    /// vertices that are already connected is rejected.
+    #[inline]
+    pub fn undirected() -> Self {
+        UnstableGraph {
+            vertices: vec![],
+            edges: vec![],
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
+    }
+
+    /// Creates a new undirected acyclic [`UnstableGraph`] with a preallocated
+    /// capacity for `cap` vertices and `cap` edges.
+    #[inline]
+    pub fn undirected_with_capacity(cap: usize) -> Self {
+        UnstableGraph {
+            vertices: Vec::with_capacity(cap),
+            edges: Vec::with_capacity(cap),
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
+    }
+
+    /// Creates a new undirected acyclic [`UnstableGraph`] with a preallocated
+    /// capacity for `cap` vertices and approximately `cap * ratio` edges.
+    /// This method can be used to further optimise to reduce allocations when the
+    /// approximate ratio of vertices to edges is known in addition to the approximate
+    /// final number of vertices.
+    pub fn undirected_with_capacity_and_factor(cap: usize, ratio: f32) -> Self {
+        let edge_cap = (cap as f32 * ratio) as usize;
+
+        UnstableGraph {
+            vertices: Vec::with_capacity(cap),
+            edges: Vec::with_capacity(edge_cap),
+            _directed: PhantomData,
+            _cyclic: PhantomData,
+            _policy: PhantomData,
+            components: None,
+        }
+    }
+}
+
+/// An iterator over the edges that leave or enter a vertex of an [`UnstableGraph`], paired with
+/// the vertex at their other end.
+#[derive(Clone, Debug)]
+pub struct Edges<'a, E: Clone, Ix: IndexType> {
+    outgoing: std::slice::Iter<'a, EdgeIndex<Ix>>,
+    incoming: std::slice::Iter<'a, EdgeIndex<Ix>>,
+    skip_loops: bool,
+    edges: &'a [Edge<E, Ix>],
+}
+
+impl<'a, E: Clone, Ix: IndexType> Iterator for Edges<'a, E, Ix> {
+    type Item = (EdgeIndex<Ix>, VertexIndex<Ix>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(&index) = self.outgoing.next() {
+            return Some((index, self.edges[index.index()].vertex_indices[1]));
+        }
+
+        for &index in self.incoming.by_ref() {
+            let [source, target] = self.edges[index.index()].vertex_indices;
+
+            if !self.skip_loops || source != target {
+                return Some((index, source));
+            }
+        }
+
+        None
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> GraphBase
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type VertexId = VertexIndex<Ix>;
+    type EdgeId = EdgeIndex<Ix>;
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> GraphProp
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type EdgeType = D;
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> Data
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type VertexWeight = V;
+    type EdgeWeight = E;
+
+    #[inline]
+    fn vertex_weight(&self, vertex: VertexIndex<Ix>) -> Option<&V> {
+        self.vertices
+            .get(vertex.index())
+            .map(|vertex| &vertex.weight)
+    }
+
+    #[inline]
+    fn edge_weight(&self, edge: EdgeIndex<Ix>) -> Option<&E> {
+        self.edges.get(edge.index()).map(|edge| &edge.weight)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> Counts
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    #[inline]
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> VertexIds
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type VertexIds<'a>
+        = Map<Range<usize>, fn(usize) -> VertexIndex<Ix>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn vertex_ids(&self) -> Self::VertexIds<'_> {
+        (0..self.vertices.len()).map(VertexIndex::new)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> EdgeIds
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type EdgeIds<'a>
+        = Map<Range<usize>, fn(usize) -> EdgeIndex<Ix>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn edge_ids(&self) -> Self::EdgeIds<'_> {
+        (0..self.edges.len()).map(EdgeIndex::new)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> EdgeEndpoints
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    #[inline]
+    fn edge_endpoints(&self, edge: EdgeIndex<Ix>) -> Option<(VertexIndex<Ix>, VertexIndex<Ix>)> {
+        self.edges.get(edge.index()).map(Edge::vertex_indices)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> IncidentEdges
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type Edges<'a>
+        = Edges<'a, E, Ix>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is out of bounds.
+    #[inline]
+    fn edges_directed(&self, vertex: VertexIndex<Ix>, direction: Direction) -> Edges<'_, E, Ix> {
+        let vertex = &self.vertices[vertex.index()];
+        let (outgoing, incoming) = match (D::is_directed(), direction) {
+            (false, _) => (vertex.edge_indices(Outgoing), vertex.edge_indices(Incoming)),
+            (true, Outgoing) => (vertex.edge_indices(Outgoing), &[][..]),
+            (true, Incoming) => (&[][..], vertex.edge_indices(Incoming)),
+        };
+
+        Edges {
+            outgoing: outgoing.iter(),
+            incoming: incoming.iter(),
+            skip_loops: !D::is_directed(),
+            edges: &self.edges,
+        }
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> Neighbors
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type Neighbors<'a>
+        = Map<Edges<'a, E, Ix>, fn((EdgeIndex<Ix>, VertexIndex<Ix>)) -> VertexIndex<Ix>>
+    where
+        Self: 'a;
+
+    /// # Panics:
+    /// Panics if `vertex` is out of bounds.
+    #[inline]
+    fn neighbors_directed(
+        &self,
+        vertex: VertexIndex<Ix>,
+        direction: Direction,
+    ) -> Self::Neighbors<'_> {
+        self.edges_directed(vertex, direction)
+            .map(|(_, neighbour)| neighbour)
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType> Visitable
+    for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type Map = Vec<bool>;
+
+    #[inline]
+    fn visit_map(&self) -> Vec<bool> {
+        vec![false; self.vertices.len()]
+    }
+
+    #[inline]
+    fn reset_map(&self, map: &mut Vec<bool>) {
+        map.clear();
+        map.resize(self.vertices.len(), false);
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType>
+    Index<VertexIndex<Ix>> for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type Output = V;
+
+    /// Returns the weight of the [`Vertex`] at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: VertexIndex<Ix>) -> &V {
+        &self.vertices[index.index()].weight
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType>
+    IndexMut<VertexIndex<Ix>> for UnstableGraph<V, E, D, C, P, Ix>
+{
+    /// Returns the weight of the [`Vertex`] at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: VertexIndex<Ix>) -> &mut V {
+        &mut self.vertices[index.index()].weight
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType>
+    Index<EdgeIndex<Ix>> for UnstableGraph<V, E, D, C, P, Ix>
+{
+    type Output = E;
+
+    /// Returns the weight of the [`Edge`] at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.edges[index.index()].weight
+    }
+}
+
+impl<V: Clone, E: Clone, D: EdgeType, C: Cyclicness, P: EdgePolicy, Ix: IndexType>
+    IndexMut<EdgeIndex<Ix>> for UnstableGraph<V, E, D, C, P, Ix>
+{
+    /// Returns the weight of the [`Edge`] at `index`.
+    ///
+    /// # Panics:
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: EdgeIndex<Ix>) -> &mut E {
+        &mut self.edges[index.index()].weight
+    }
+}
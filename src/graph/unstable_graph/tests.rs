@@ -1,25 +1,299 @@
 #![cfg(test)]
 
 use super::*;
-use crate::graph::unstable_graph::dft::Dft;
 
 #[test]
 fn create_graph() {
     let mut graph = UnstableGraph::<usize, usize, Directed, Cyclic>::directed();
 
-    let v1 = graph.insert_vertex(10);
-    let v2 = graph.insert_vertex(20);
-    let v3 = graph.insert_vertex(30);
-    let v4 = graph.insert_vertex(40);
+    let v1 = graph.insert_vertex(10).unwrap();
+    let v2 = graph.insert_vertex(20).unwrap();
+    let v3 = graph.insert_vertex(30).unwrap();
+    let v4 = graph.insert_vertex(40).unwrap();
 
     graph.insert_edge(v1, v2, 25).unwrap();
     graph.insert_edge(v2, v3, 50).unwrap();
     graph.insert_edge(v3, v4, 75).unwrap();
     graph.insert_edge(v4, v3, 100).unwrap();
 
-    let traverser = Dft::new_acyclic(&graph);
+    assert_eq!((graph.vertex_count(), graph.edge_count()), (4, 4));
+    assert_eq!(
+        [v1, v2, v3, v4].map(|vertex| graph[vertex]),
+        [10, 20, 30, 40]
+    );
 
-    for (index, vertex) in traverser.enumerate() {
-        eprintln!("{index}: {:#?}", vertex);
+    let edges = graph
+        .edge_ids()
+        .map(|edge| (graph.edge_endpoints(edge).unwrap(), graph[edge]))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        edges,
+        [
+            ((v1, v2), 25),
+            ((v2, v3), 50),
+            ((v3, v4), 75),
+            ((v4, v3), 100)
+        ]
+    );
+}
+
+/// Checks that every edge is listed by exactly its endpoints, in the right direction.
+fn assert_consistent<V: Clone, E: Clone, D: EdgeType, C: Cyclicness>(
+    graph: &UnstableGraph<V, E, D, C>,
+) {
+    for (index, vertex) in graph.vertices.iter().enumerate() {
+        let index = VertexIndex::new(index);
+
+        for &edge in vertex.edge_indices(Direction::Outgoing) {
+            assert_eq!(graph.edges[edge.index()].vertex_indices[0], index);
+        }
+
+        for &edge in vertex.edge_indices(Direction::Incoming) {
+            assert_eq!(graph.edges[edge.index()].vertex_indices[1], index);
+        }
+    }
+
+    for (index, edge) in graph.edges.iter().enumerate() {
+        let index = EdgeIndex::new(index);
+        let [source, target] = edge.vertex_indices;
+        assert!(graph.vertices[source.index()]
+            .edge_indices(Direction::Outgoing)
+            .contains(&index));
+        assert!(graph.vertices[target.index()]
+            .edge_indices(Direction::Incoming)
+            .contains(&index));
+    }
+}
+
+#[test]
+fn remove() {
+    let mut graph = UnstableGraph::<char, u32, Directed, Cyclic>::directed();
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|weight| graph.insert_vertex(weight).unwrap());
+
+    let [_, bc, _, cd, dd] = [(a, b), (b, c), (c, a), (c, d), (d, d)].map(|(source, target)| {
+        let weight = source.index() * 10 + target.index();
+        graph.insert_edge(source, target, weight as u32).unwrap()
+    });
+
+    assert_eq!(
+        graph.remove_edge(bc),
+        Some(Removed {
+            weight: 12,
+            moved: Some(dd)
+        })
+    );
+    assert_eq!(graph[bc], 33);
+    assert_consistent(&graph);
+
+    assert_eq!(
+        graph.remove_edge(cd).map(|removed| removed.moved),
+        Some(None)
+    );
+    assert_eq!(graph.remove_edge(cd), None);
+    assert_consistent(&graph);
+
+    // Removing `a` removes its two edges and moves `d` into its place.
+    assert_eq!(
+        graph.remove_vertex(a),
+        Some(Removed {
+            weight: 'a',
+            moved: Some(d)
+        })
+    );
+    assert_eq!(graph[a], 'd');
+    assert_eq!(graph.edge_count(), 1);
+    assert_eq!(graph.edges[0].vertex_indices(), (a, a));
+    assert_consistent(&graph);
+
+    assert_eq!(graph.remove_vertex(d), None);
+    assert_eq!(
+        graph.remove_vertex(c).map(|removed| removed.moved),
+        Some(None)
+    );
+    assert_consistent(&graph);
+}
+
+#[test]
+fn insert_edge_errors() {
+    let mut graph = UnstableGraph::<(), (), Directed, Acyclic>::directed();
+    let [a, b, c] = [(); 3].map(|_| graph.insert_vertex(()).unwrap());
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(b, c, ()).unwrap();
+
+    let missing = VertexIndex::new(3);
+    assert_eq!(
+        graph.insert_edge(a, missing, ()),
+        Err(GraphError::InvalidVertex(missing))
+    );
+    assert_eq!(
+        graph.insert_edge(c, a, ()),
+        Err(GraphError::WouldCycle(vec![a, b, c]))
+    );
+    assert_eq!(
+        graph.insert_edge(b, b, ()),
+        Err(GraphError::WouldCycle(vec![b]))
+    );
+    assert!(graph.insert_edge(a, c, ()).is_ok());
+}
+
+#[test]
+fn compact_indices() {
+    let mut graph = UnstableGraph::<(), (), Directed, Cyclic, Pseudo, u16>::directed();
+    let vertices = (0..=u16::MAX as usize)
+        .map(|_| graph.insert_vertex(()).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vertices.last().map(|vertex| vertex.index()), Some(65535));
+    assert_eq!(graph.insert_vertex(()), Err(GraphError::GraphFull));
+    assert_eq!(graph.vertex_count(), 65536);
+
+    for (index, &source) in vertices.iter().enumerate() {
+        let target = vertices[(index + 1) % vertices.len()];
+        graph.insert_edge(source, target, ()).unwrap();
     }
+
+    assert_eq!(
+        graph.insert_edge(vertices[0], vertices[1], ()),
+        Err(GraphError::GraphFull)
+    );
+    assert_eq!(graph.edge_count(), 65536);
+}
+
+#[test]
+fn edge_policies() {
+    let mut simple = UnstableGraph::<(), (), Undirected, Cyclic, Simple>::undirected();
+    let [a, b] = [(); 2].map(|_| simple.insert_vertex(()).unwrap());
+    simple.insert_edge(a, b, ()).unwrap();
+
+    assert_eq!(
+        simple.insert_edge(b, a, ()),
+        Err(GraphError::ParallelEdge(b, a))
+    );
+    assert_eq!(simple.insert_edge(a, a, ()), Err(GraphError::SelfLoop(a)));
+    assert_eq!(simple.edge_count(), 1);
+
+    let mut looped = UnstableGraph::<(), (), Directed, Cyclic, Looped>::directed();
+    let [a, b] = [(); 2].map(|_| looped.insert_vertex(()).unwrap());
+    looped.insert_edge(a, a, ()).unwrap();
+    looped.insert_edge(a, b, ()).unwrap();
+    looped.insert_edge(b, a, ()).unwrap();
+    assert_eq!(
+        looped.insert_edge(a, a, ()),
+        Err(GraphError::ParallelEdge(a, a))
+    );
+
+    let mut multi = UnstableGraph::<(), (), Directed, Acyclic, Multi>::directed();
+    let [a, b] = [(); 2].map(|_| multi.insert_vertex(()).unwrap());
+    multi.insert_edge(a, b, ()).unwrap();
+    multi.insert_edge(a, b, ()).unwrap();
+    assert_eq!(multi.insert_edge(b, b, ()), Err(GraphError::SelfLoop(b)));
+}
+
+#[test]
+fn forest() {
+    let mut graph = UnstableGraph::<(), (), Undirected, Acyclic>::undirected();
+    let [a, b, c, d] = [(); 4].map(|_| graph.insert_vertex(()).unwrap());
+    graph.insert_edge(a, b, ()).unwrap();
+    graph.insert_edge(c, b, ()).unwrap();
+
+    assert_eq!(
+        graph.insert_edge(a, c, ()),
+        Err(GraphError::WouldCycle(vec![c, b, a]))
+    );
+    assert_eq!(
+        graph.insert_edge(b, a, ()),
+        Err(GraphError::WouldCycle(vec![a, b]))
+    );
+    assert_eq!(
+        graph.insert_edge(d, d, ()),
+        Err(GraphError::WouldCycle(vec![d]))
+    );
+    assert!(!graph.cycles());
+
+    // Removing an edge splits its tree, so the same vertices can be joined another way.
+    let ab = graph
+        .edges_directed(a, Direction::Outgoing)
+        .next()
+        .unwrap()
+        .0;
+    graph.remove_edge(ab);
+    graph.insert_edge(a, c, ()).unwrap();
+    graph.insert_edge(d, a, ()).unwrap();
+    assert_eq!(graph.edge_count(), 3);
+    assert!(!graph.cycles());
+}
+
+#[test]
+fn cycles() {
+    let mut directed = UnstableGraph::<(), (), Directed, Cyclic>::directed();
+    assert!(!directed.cycles());
+
+    let [a, b, c] = [(); 3].map(|_| directed.insert_vertex(()).unwrap());
+    directed.insert_edge(a, b, ()).unwrap();
+    directed.insert_edge(a, c, ()).unwrap();
+    directed.insert_edge(b, c, ()).unwrap();
+    assert!(!directed.cycles());
+
+    directed.insert_edge(c, a, ()).unwrap();
+    assert!(directed.cycles());
+
+    let mut undirected = UnstableGraph::<(), (), Undirected, Cyclic>::undirected();
+    let [a, b, c] = [(); 3].map(|_| undirected.insert_vertex(()).unwrap());
+    undirected.insert_edge(a, b, ()).unwrap();
+    undirected.insert_edge(c, b, ()).unwrap();
+    assert!(!undirected.cycles());
+
+    undirected.insert_edge(b, a, ()).unwrap();
+    assert!(undirected.cycles());
+}
+
+#[test]
+fn subgraphs() {
+    let mut graph = UnstableGraph::<char, u32, Directed, Acyclic>::directed();
+    let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|weight| graph.insert_vertex(weight).unwrap());
+    let ab = graph.insert_edge(a, b, 1).unwrap();
+    let bd = graph.insert_edge(b, d, 2).unwrap();
+    let ad = graph.insert_edge(a, d, 3).unwrap();
+    graph.insert_edge(c, d, 4).unwrap();
+
+    let (mut induced, map) = graph.induced_subgraph([d, a, b]).unwrap();
+    assert_eq!(induced.vertex_count(), 3);
+    assert_eq!(induced.edge_count(), 3);
+    assert_eq!(map.vertex(c), None);
+
+    // Indices are compacted, so `d` moves into the slot that `c` left behind.
+    let new_d = map.vertex(d).unwrap();
+    assert_eq!(new_d, VertexIndex::new(2));
+    assert_eq!(map.original_vertex(new_d), Some(d));
+    assert_eq!(induced[new_d], 'd');
+    assert_eq!(induced[map.edge(ad).unwrap()], 3);
+    assert_consistent(&induced);
+
+    // The subgraph keeps the typestates of the graph that it was taken from.
+    let new_a = map.vertex(a).unwrap();
+    assert!(matches!(
+        induced.insert_edge(new_d, new_a, 0),
+        Err(GraphError::WouldCycle(_))
+    ));
+
+    let (edges, map) = graph.edge_subgraph([bd]).unwrap();
+    assert_eq!(edges.vertex_count(), 2);
+    assert_eq!(edges.edge_count(), 1);
+    assert_eq!(map.edge(ab), None);
+    assert_eq!(map.original_edge(EdgeIndex::new(0)), Some(bd));
+    assert_eq!(
+        edges.edge_endpoints(EdgeIndex::new(0)),
+        Some((map.vertex(b).unwrap(), map.vertex(d).unwrap()))
+    );
+
+    let missing = VertexIndex::new(4);
+    assert_eq!(
+        graph.induced_subgraph([a, missing]).err(),
+        Some(GraphError::InvalidVertex(missing))
+    );
+    let missing = EdgeIndex::new(4);
+    assert_eq!(
+        graph.edge_subgraph([missing]).err(),
+        Some(GraphError::InvalidEdge(missing))
+    );
 }
@@ -1,5 +1,7 @@
 #![doc = include_str!("../README.md")]
 
+/// Graffy's graph algorithms.
+pub mod algo;
 /// Graffy's graph types.
 pub mod graph;
 /// Commonly used types and functions.
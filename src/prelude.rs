@@ -1 +1,15 @@
-
+pub use crate::graph::{
+    adapters::{AsUndirected, Filtered, Reversed},
+    csr_graph::CsrGraph,
+    graph_map::GraphMap,
+    matrix_graph::{MatrixEdge, MatrixGraph},
+    stable_graph::{EdgeKey, StableGraph, VertexKey},
+    traits::{
+        Counts, Data, EdgeEndpoints, EdgeIds, Graph, GraphBase, GraphProp, IncidentEdges,
+        Neighbors, VertexIds, VisitMap, Visitable,
+    },
+    tree::{StableTree, Tree, UnstableTree},
+    unstable_graph::{EdgeIndex, IndexType, UnstableGraph, VertexIndex},
+    Acyclic, Cyclic, Cyclicness, Directed, Direction, EdgePolicy, EdgeType, GraphError, Looped,
+    Multi, Pseudo, Simple, Subgraph, SubgraphMap, Undirected,
+};